@@ -0,0 +1,692 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::{
+    prelude::Pubkey, solana_program::instruction::{AccountMeta, Instruction}, system_program, AccountDeserialize,
+    Discriminator, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, spl_associated_token_account},
+    token,
+};
+use realvue::{Category, CategoryPolicy, MerchantCounter, PlatformConfig, ReviewAccount, ReviewCampaign, RewardPayout};
+use serde_json::{json, Value};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+use realvue_client::{pda, Result, RpcClient};
+
+use crate::output::{self, OutputFormat};
+
+pub struct Context {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub output: OutputFormat,
+}
+
+impl Context {
+    pub fn init_platform(&self, seed: u64, fee_bps: u16) -> Result<()> {
+        let admin = self.payer.pubkey();
+        let platform = pda::platform(seed, &admin);
+
+        let accounts = realvue::accounts::InitializePlatform {
+            admin,
+            platform,
+            rev_mint: pda::rev_mint(&platform),
+            treasury: pda::treasury(&platform),
+            token_program: token::ID,
+            system_program: system_program::ID,
+        };
+
+        self.send(accounts, realvue::instruction::InitPlatform { seed, platform_fee: fee_bps })?;
+
+        let config = self.fetch::<PlatformConfig>(&platform)?;
+        output::print(self.output, &platform_json(&platform, &config, self.rpc.get_balance(&pda::treasury(&platform))?));
+
+        Ok(())
+    }
+
+    pub fn create_category(
+        &self,
+        platform: Pubkey,
+        name: String,
+        fee_bps: Option<u16>,
+        min_deposit: f64,
+        min_reviewer_rank: u8,
+        max_description_len: u16,
+    ) -> Result<()> {
+        let category = pda::category(&platform, &name);
+
+        let accounts = realvue::accounts::CreateCategory {
+            admin: self.payer.pubkey(),
+            category,
+            platform,
+            system_program: system_program::ID,
+        };
+
+        let policy = CategoryPolicy {
+            fee_bps,
+            min_deposit: to_lamports(min_deposit),
+            min_reviewer_rank,
+            max_description_len,
+        };
+
+        self.send(accounts, realvue::instruction::CreateCategory { name, policy })?;
+
+        let category_state = self.fetch::<Category>(&category)?;
+        output::print(self.output, &json!({
+            "address": category.to_string(),
+            "name": category_state.name,
+            "fee_bps": category_state.policy.fee_bps,
+            "min_deposit_sol": to_sol(category_state.policy.min_deposit),
+            "min_reviewer_rank": category_state.policy.min_reviewer_rank,
+            "max_description_len": category_state.policy.max_description_len,
+            "active": category_state.active,
+        }));
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_campaign(
+        &self,
+        platform: Pubkey,
+        category: String,
+        name: String,
+        product_id: String,
+        deposit: f64,
+        reviews_needed: u16,
+        start: Option<i64>,
+        days: i64,
+    ) -> Result<()> {
+        let merchant = self.payer.pubkey();
+        let merchant_counter = pda::merchant_counter(&merchant);
+        let rev_mint = pda::rev_mint(&platform);
+
+        // The counter is created along with the merchant's first campaign
+        let campaign_id = match self.rpc.get_account(&merchant_counter)? {
+            Some(_) => self.fetch::<MerchantCounter>(&merchant_counter)?.next_campaign_id(),
+            None => 1,
+        };
+        let campaign = pda::campaign(&merchant, campaign_id);
+
+        let start_time = match start {
+            Some(start) => start,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+        };
+
+        let accounts = realvue::accounts::CreateCampaign {
+            merchant,
+            merchant_counter,
+            campaign,
+            merchant_account: pda::merchant_account(&platform, &merchant),
+            merchant_ata: get_associated_token_address_with_program_id(&merchant, &rev_mint, &token::ID),
+            platform,
+            rev_mint,
+            vault: pda::vault(&campaign),
+            treasury: pda::treasury(&platform),
+            category: pda::category(&platform, &category),
+            referrer_account: None,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        };
+
+        let args = realvue::instruction::CreateCampaign {
+            name,
+            product_id,
+            deposit_amount: to_lamports(deposit),
+            start_time,
+            end_time: start_time + days * 24 * 60 * 60,
+            reviews_needed,
+            vesting: None,
+        };
+
+        self.send(accounts, args)?;
+
+        self.print_campaign(&campaign)
+    }
+
+    pub fn show_campaign(&self, merchant: Pubkey, campaign_id: u64) -> Result<()> {
+        self.print_campaign(&pda::campaign(&merchant, campaign_id))
+    }
+
+    pub fn rename_campaign(&self, campaign: Pubkey, name: String) -> Result<()> {
+        let accounts = realvue::accounts::RenameCampaign {
+            merchant: self.payer.pubkey(),
+            campaign,
+        };
+
+        self.send(accounts, realvue::instruction::RenameCampaign { name })?;
+
+        self.print_campaign(&campaign)
+    }
+
+    pub fn list_campaigns(&self, merchant: Option<Pubkey>) -> Result<()> {
+        let mut filters: Vec<(usize, &[u8])> = vec![(0, ReviewCampaign::DISCRIMINATOR)];
+
+        // merchant follows the discriminator and the version byte
+        if let Some(merchant) = merchant.as_ref() {
+            filters.push((8 + 1, merchant.as_ref()));
+        }
+
+        let mut rows = Vec::new();
+        for (address, account) in self.rpc.get_program_accounts(&realvue::ID, &filters)? {
+            // Campaigns that still need migrate_campaign don't decode with the current layout
+            let Ok(campaign) = ReviewCampaign::try_deserialize(&mut account.data.as_slice()) else {
+                continue;
+            };
+
+            rows.push(json!({
+                "address": address.to_string(),
+                "id": campaign.campaign_id,
+                "name": campaign.name,
+                "merchant": campaign.merchant.to_string(),
+                "deposit_sol": to_sol(campaign.deposit_amount),
+                "approved": format!("{}/{}", campaign.approved_count, campaign.reviews_needed),
+                "pending": campaign.pending_reviews,
+                "status": campaign_status(&campaign),
+            }));
+        }
+
+        output::print(self.output, &Value::Array(rows));
+
+        Ok(())
+    }
+
+    pub fn review(&self, platform: Pubkey, campaign: Pubkey, description: String, tx_id: String) -> Result<()> {
+        let reviewer = self.payer.pubkey();
+        let reviewer_account = pda::reviewer_account(&platform, &reviewer);
+        let review_account = pda::review(&campaign, &reviewer);
+
+        // Campaigns migrated from before categories don't have one
+        let category = self.fetch::<ReviewCampaign>(&campaign)?.category;
+        let category = (category != Pubkey::default()).then_some(category);
+
+        let mut instructions = Vec::new();
+
+        if self.rpc.get_account(&reviewer_account)?.is_none() {
+            let accounts = realvue::accounts::InitializeReviewer {
+                payer: reviewer,
+                reviewer,
+                reviewer_account,
+                platform,
+                system_program: system_program::ID,
+            };
+            instructions.push(instruction(accounts, realvue::instruction::InitReviewer {}));
+        }
+
+        let accounts = realvue::accounts::MakeReview {
+            payer: reviewer,
+            reviewer,
+            review_account,
+            campaign,
+            vault: pda::vault(&campaign),
+            reviewer_account,
+            platform,
+            category,
+            bond_vault: pda::bond_vault(&review_account),
+            purchase_record: pda::purchase_record(&campaign, &tx_id),
+            system_program: system_program::ID,
+        };
+        instructions.push(instruction(accounts, realvue::instruction::MakeReview { description, tx_id }));
+
+        self.send_all(&instructions)?;
+
+        self.print_review(&review_account)
+    }
+
+    pub fn moderate(
+        &self,
+        platform: Pubkey,
+        campaign: Pubkey,
+        reviewer: Pubkey,
+        approve: bool,
+        flag: Option<String>,
+    ) -> Result<()> {
+        let review_account = pda::review(&campaign, &reviewer);
+        let campaign_state = self.fetch::<ReviewCampaign>(&campaign)?;
+        let review = self.fetch::<ReviewAccount>(&review_account)?;
+
+        let accounts = realvue::accounts::ApproveReview {
+            admin: self.payer.pubkey(),
+            review_account,
+            campaign,
+            reviewer_account: pda::reviewer_account(&platform, &reviewer),
+            platform,
+            bond_vault: pda::bond_vault(&review_account),
+            reviewer,
+            merchant: campaign_state.merchant,
+            treasury: pda::treasury(&platform),
+            purchase_record: pda::purchase_record(&campaign, &review.tx_id),
+            system_program: system_program::ID,
+        };
+
+        let mut approve_ix = instruction(accounts, realvue::instruction::ApproveReview { approved: approve, flagged_reason: flag });
+
+        // The program recounts attestations from the platform's current attestors
+        let platform_state = self.fetch::<PlatformConfig>(&platform)?;
+        for attestor in &platform_state.attestors {
+            let attestation = pda::attestation(&review_account, attestor);
+            if self.rpc.get_account(&attestation)?.is_some() {
+                approve_ix.accounts.push(AccountMeta::new_readonly(attestation, false));
+            }
+        }
+
+        self.send_all(&[approve_ix])?;
+
+        self.print_review(&review_account)
+    }
+
+    pub fn claim(&self, platform: Pubkey, campaign: Pubkey, rev: bool) -> Result<()> {
+        let reviewer = self.payer.pubkey();
+        let sponsor = self.fetch::<PlatformConfig>(&platform)?.review_sponsor;
+
+        self.send_all(&claim_instructions(&platform, &campaign, &reviewer, sponsor, rev))?;
+
+        self.print_review(&pda::review(&campaign, &reviewer))
+    }
+
+    pub fn refund(&self, platform: Pubkey, campaign: Pubkey) -> Result<()> {
+        let accounts = realvue::accounts::RefundDeposit {
+            merchant: self.payer.pubkey(),
+            campaign,
+            vault: pda::vault(&campaign),
+            platform,
+            system_program: system_program::ID,
+        };
+
+        self.send(accounts, realvue::instruction::RefundDeposit {})?;
+
+        // The campaign is closed once nothing is left owed to reviewers
+        match self.rpc.get_account(&campaign)? {
+            Some(_) => self.print_campaign(&campaign),
+            None => {
+                output::print(self.output, &json!({ "campaign": campaign.to_string(), "status": "closed" }));
+                Ok(())
+            }
+        }
+    }
+
+    pub fn treasury(
+        &self,
+        platform: Pubkey,
+        withdraw: Option<f64>,
+        destination: Option<Pubkey>,
+        execute: bool,
+        cancel: bool,
+    ) -> Result<()> {
+        let treasury = pda::treasury(&platform);
+        let admin = self.payer.pubkey();
+
+        if let Some(amount) = withdraw {
+            let config = self.fetch::<PlatformConfig>(&platform)?;
+            let destination = destination.unwrap_or(admin);
+            self.send_all(&[withdraw_instruction(&platform, &config, &admin, to_lamports(amount), destination)])?;
+        } else if execute {
+            // The destination was fixed when the withdrawal was requested
+            let config = self.fetch::<PlatformConfig>(&platform)?;
+            let pending = config.pending_withdrawal.ok_or("no pending withdrawal to execute")?;
+
+            let accounts = realvue::accounts::ExecuteWithdrawal {
+                admin,
+                platform,
+                treasury,
+                destination: pending.destination,
+                system_program: system_program::ID,
+            };
+
+            self.send(accounts, realvue::instruction::ExecuteWithdrawal {})?;
+        } else if cancel {
+            let accounts = realvue::accounts::CancelWithdrawal {
+                admin,
+                platform,
+                system_program: system_program::ID,
+            };
+
+            self.send(accounts, realvue::instruction::CancelWithdrawal {})?;
+        }
+
+        let config = self.fetch::<PlatformConfig>(&platform)?;
+        output::print(self.output, &platform_json(&platform, &config, self.rpc.get_balance(&treasury)?));
+
+        Ok(())
+    }
+
+    fn send(&self, accounts: impl ToAccountMetas, args: impl InstructionData) -> Result<()> {
+        self.send_all(&[instruction(accounts, args)])
+    }
+
+    fn send_all(&self, instructions: &[Instruction]) -> Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        let signature = self.rpc.send_and_confirm(&transaction)?;
+
+        // Keep stdout clean for --output json
+        eprintln!("signature: {signature}");
+
+        Ok(())
+    }
+
+    fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let account = self
+            .rpc
+            .get_account(address)?
+            .ok_or_else(|| format!("account {address} not found"))?;
+
+        if account.owner != realvue::ID {
+            return Err(format!("account {address} is not owned by realvue").into());
+        }
+
+        Ok(T::try_deserialize(&mut account.data.as_slice())?)
+    }
+
+    fn print_campaign(&self, address: &Pubkey) -> Result<()> {
+        let campaign = self.fetch::<ReviewCampaign>(address)?;
+        let vault_balance = self.rpc.get_balance(&pda::vault(address))?;
+
+        output::print(self.output, &json!({
+            "address": address.to_string(),
+            "id": campaign.campaign_id,
+            "name": campaign.name,
+            "product_id": campaign.product_id,
+            "merchant": campaign.merchant.to_string(),
+            "platform": campaign.platform.to_string(),
+            "deposit_sol": to_sol(campaign.deposit_amount),
+            "vault_sol": to_sol(vault_balance),
+            "fee_bps": campaign.fee_bps,
+            "start_time": campaign.start_time,
+            "end_time": campaign.end_time,
+            "reviews_needed": campaign.reviews_needed,
+            "approved": campaign.approved_count,
+            "pending": campaign.pending_reviews,
+            "rewards_claimed": campaign.rewards_claimed,
+            "status": campaign_status(&campaign),
+        }));
+
+        Ok(())
+    }
+
+    fn print_review(&self, address: &Pubkey) -> Result<()> {
+        let review = self.fetch::<ReviewAccount>(address)?;
+
+        let status = match (review.approved, review.flagged_reason.is_empty()) {
+            (true, _) => "approved",
+            (false, false) => "flagged",
+            (false, true) => "pending",
+        };
+
+        output::print(self.output, &json!({
+            "address": address.to_string(),
+            "campaign": review.campaign_id.to_string(),
+            "reviewer": review.reviewer.to_string(),
+            "status": status,
+            "flagged_reason": review.flagged_reason,
+            "reward_claimed": review.reward_claimed,
+            "reviewer_rank": review.reviewer_rank,
+            "tx_id": review.tx_id,
+            "description": review.description,
+        }));
+
+        Ok(())
+    }
+}
+
+fn instruction(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: realvue::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+// claim_fee only works without a timelock, otherwise the withdrawal is queued for `treasury --execute`
+fn withdraw_instruction(platform: &Pubkey, config: &PlatformConfig, admin: &Pubkey, amount: u64, destination: Pubkey) -> Instruction {
+    if config.withdrawal_timelock == 0 {
+        let accounts = realvue::accounts::ClaimFee {
+            admin: *admin,
+            platform: *platform,
+            treasury: pda::treasury(platform),
+            destination,
+            system_program: system_program::ID,
+        };
+
+        return instruction(accounts, realvue::instruction::ClaimFee { amount });
+    }
+
+    let accounts = realvue::accounts::RequestWithdrawal {
+        admin: *admin,
+        platform: *platform,
+        treasury: pda::treasury(platform),
+        system_program: system_program::ID,
+    };
+
+    instruction(accounts, realvue::instruction::RequestWithdrawal { amount, destination })
+}
+
+fn claim_instructions(platform: &Pubkey, campaign: &Pubkey, reviewer: &Pubkey, sponsor: Option<Pubkey>, rev: bool) -> Vec<Instruction> {
+    let rev_mint = pda::rev_mint(platform);
+    let reviewer_rev_account = get_associated_token_address_with_program_id(reviewer, &rev_mint, &token::ID);
+
+    let mut instructions = Vec::new();
+
+    // REV lands in the reviewer's associated token account, created on first use
+    if rev {
+        instructions.push(spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            reviewer,
+            reviewer,
+            &rev_mint,
+            &token::ID,
+        ));
+    }
+
+    let accounts = realvue::accounts::ClaimReward {
+        reviewer: *reviewer,
+        review_account: pda::review(campaign, reviewer),
+        campaign: *campaign,
+        vault: pda::vault(campaign),
+        reviewer_account: pda::reviewer_account(platform, reviewer),
+        platform: *platform,
+        // Repaid out of the reward while the reviewer still owes sponsored rent
+        sponsor,
+        rev_mint: rev.then_some(rev_mint),
+        reviewer_rev_account: rev.then_some(reviewer_rev_account),
+        token_program: rev.then_some(token::ID),
+        system_program: system_program::ID,
+    };
+
+    let payout = match rev {
+        true => RewardPayout::Rev,
+        false => RewardPayout::Sol,
+    };
+    instructions.push(instruction(accounts, realvue::instruction::ClaimReward { payout }));
+
+    instructions
+}
+
+fn to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+fn to_lamports(sol: f64) -> u64 {
+    (sol * LAMPORTS_PER_SOL as f64).round() as u64
+}
+
+fn campaign_status(campaign: &ReviewCampaign) -> &'static str {
+    if campaign.refunded {
+        "refunded"
+    } else if campaign.cancelled {
+        "cancelled"
+    } else if campaign.active {
+        "active"
+    } else {
+        "closed"
+    }
+}
+
+fn platform_json(address: &Pubkey, platform: &PlatformConfig, treasury_balance: u64) -> Value {
+    json!({
+        "address": address.to_string(),
+        "admin": platform.admin.to_string(),
+        "rev_mint": platform.rev_mint.to_string(),
+        "fee_bps": platform.platform_fee,
+        "active": platform.is_active,
+        "total_campaigns": platform.total_campaigns,
+        "total_reviews": platform.total_reviews,
+        "fees_collected_sol": to_sol(platform.total_fees_collected),
+        "fees_withdrawn_sol": to_sol(platform.total_fees_withdrawn),
+        "treasury_sol": to_sol(treasury_balance),
+        "withdrawal_timelock": platform.withdrawal_timelock,
+        "pending_timelock": platform.pending_timelock.map(|pending| json!({
+            "timelock": pending.timelock,
+            "effective_at": pending.effective_at,
+        })),
+        "pending_withdrawal": platform.pending_withdrawal.map(|pending| json!({
+            "amount_sol": to_sol(pending.amount),
+            "destination": pending.destination.to_string(),
+            "executable_at": pending.executable_at,
+        })),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Space;
+
+    use super::*;
+
+    fn blank_campaign() -> ReviewCampaign {
+        let mut data = ReviewCampaign::DISCRIMINATOR.to_vec();
+        data.resize(8 + ReviewCampaign::INIT_SPACE, 0);
+        ReviewCampaign::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn converts_between_sol_and_lamports() {
+        assert_eq!(to_lamports(1.0), LAMPORTS_PER_SOL);
+        assert_eq!(to_lamports(0.1), 100_000_000);
+        assert_eq!(to_lamports(0.000_000_001_4), 1);
+        assert_eq!(to_sol(2_500_000_000), 2.5);
+        assert_eq!(to_lamports(to_sol(123_456_789)), 123_456_789);
+    }
+
+    #[test]
+    fn campaign_status_prefers_refunded_then_cancelled() {
+        let mut campaign = blank_campaign();
+        assert_eq!(campaign_status(&campaign), "closed");
+
+        campaign.active = true;
+        assert_eq!(campaign_status(&campaign), "active");
+
+        campaign.cancelled = true;
+        assert_eq!(campaign_status(&campaign), "cancelled");
+
+        campaign.refunded = true;
+        assert_eq!(campaign_status(&campaign), "refunded");
+    }
+
+    #[test]
+    fn instruction_targets_realvue_with_the_anchor_discriminator() {
+        let accounts = realvue::accounts::RenameCampaign {
+            merchant: Pubkey::new_unique(),
+            campaign: Pubkey::new_unique(),
+        };
+        let ix = instruction(accounts, realvue::instruction::RenameCampaign { name: "Drop".to_string() });
+
+        assert_eq!(ix.program_id, realvue::ID);
+        assert_eq!(&ix.data[..8], realvue::instruction::RenameCampaign::DISCRIMINATOR);
+        // Borsh string: u32 length then the bytes
+        assert_eq!(&ix.data[8..], &[4, 0, 0, 0, b'D', b'r', b'o', b'p']);
+        assert!(ix.accounts[0].is_signer);
+        assert!(!ix.accounts[1].is_signer && ix.accounts[1].is_writable);
+    }
+
+    #[test]
+    fn sol_claim_leaves_out_the_rev_accounts() {
+        let (platform, campaign, reviewer) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let instructions = claim_instructions(&platform, &campaign, &reviewer, None, false);
+        assert_eq!(instructions.len(), 1);
+
+        let claim = &instructions[0];
+        assert_eq!(claim.data, realvue::instruction::ClaimReward { payout: RewardPayout::Sol }.data());
+
+        let keys: Vec<Pubkey> = claim.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys[0], reviewer);
+        assert_eq!(keys[1], pda::review(&campaign, &reviewer));
+        assert_eq!(keys[4], pda::reviewer_account(&platform, &reviewer));
+        // Anchor passes the program id for optional accounts that are left out
+        assert_eq!(&keys[6..10], &[realvue::ID; 4]);
+    }
+
+    #[test]
+    fn claim_repays_the_platform_sponsor() {
+        let (platform, campaign, reviewer, sponsor) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let instructions = claim_instructions(&platform, &campaign, &reviewer, Some(sponsor), false);
+
+        let meta = &instructions[0].accounts[6];
+        assert_eq!(meta.pubkey, sponsor);
+        assert!(meta.is_writable && !meta.is_signer);
+    }
+
+    #[test]
+    fn rev_claim_creates_the_token_account_first() {
+        let (platform, campaign, reviewer) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let rev_mint = pda::rev_mint(&platform);
+
+        let instructions = claim_instructions(&platform, &campaign, &reviewer, None, true);
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, spl_associated_token_account::ID);
+
+        let keys: Vec<Pubkey> = instructions[1].accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys[7], rev_mint);
+        assert_eq!(keys[8], get_associated_token_address_with_program_id(&reviewer, &rev_mint, &token::ID));
+        assert_eq!(keys[9], token::ID);
+    }
+
+    #[test]
+    fn platform_json_shows_a_queued_timelock() {
+        let mut data = PlatformConfig::DISCRIMINATOR.to_vec();
+        data.resize(8 + PlatformConfig::INIT_SPACE, 0);
+        let mut platform = PlatformConfig::try_deserialize(&mut data.as_slice()).unwrap();
+
+        let address = Pubkey::new_unique();
+        let value = platform_json(&address, &platform, LAMPORTS_PER_SOL);
+        assert_eq!(value["address"], address.to_string());
+        assert_eq!(value["treasury_sol"], 1.0);
+        assert!(value["pending_timelock"].is_null());
+
+        platform.pending_timelock = Some(realvue::PendingTimelock { timelock: 60, effective_at: 1_000 });
+        let value = platform_json(&address, &platform, 0);
+        assert_eq!(value["pending_timelock"]["timelock"], 60);
+        assert_eq!(value["pending_timelock"]["effective_at"], 1_000);
+    }
+
+    #[test]
+    fn timelocked_withdrawals_are_requested_instead_of_claimed() {
+        let mut data = PlatformConfig::DISCRIMINATOR.to_vec();
+        data.resize(8 + PlatformConfig::INIT_SPACE, 0);
+        let mut config = PlatformConfig::try_deserialize(&mut data.as_slice()).unwrap();
+        let (platform, admin, destination) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let ix = withdraw_instruction(&platform, &config, &admin, LAMPORTS_PER_SOL, destination);
+        assert_eq!(ix.data, realvue::instruction::ClaimFee { amount: LAMPORTS_PER_SOL }.data());
+        assert_eq!(ix.accounts[3].pubkey, destination);
+
+        config.withdrawal_timelock = 86_400;
+        let ix = withdraw_instruction(&platform, &config, &admin, LAMPORTS_PER_SOL, destination);
+        assert_eq!(ix.data, realvue::instruction::RequestWithdrawal { amount: LAMPORTS_PER_SOL, destination }.data());
+        assert_eq!(ix.accounts[2].pubkey, pda::treasury(&platform));
+    }
+}
\ No newline at end of file
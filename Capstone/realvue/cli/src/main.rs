@@ -0,0 +1,330 @@
+mod commands;
+mod output;
+
+use anchor_lang::prelude::Pubkey;
+use clap::{ArgGroup, Parser, Subcommand};
+use solana_sdk::signature::read_keypair_file;
+
+use realvue_client::{Result, RpcClient};
+
+use crate::{commands::Context, output::OutputFormat};
+
+#[derive(Parser)]
+#[command(name = "realvue", version, about = "Operate a realvue platform from the command line")]
+struct Cli {
+    /// JSON-RPC endpoint, defaults to a local validator
+    #[arg(long, short = 'u', global = true, env = "REALVUE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Keypair file that signs and pays for transactions
+    #[arg(long, short = 'k', global = true, env = "REALVUE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// How to print account state
+    #[arg(long, short = 'o', global = true, value_enum, default_value = "table")]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a platform with the keypair as admin
+    InitPlatform {
+        #[arg(long)]
+        seed: u64,
+        /// Platform fee in basis points, at most 1000
+        #[arg(long)]
+        fee_bps: u16,
+    },
+    /// Add a campaign category with the keypair as platform admin
+    CreateCategory {
+        #[arg(long)]
+        platform: Pubkey,
+        #[arg(long)]
+        name: String,
+        /// Fee in basis points, replaces the platform fee and volume tiers
+        #[arg(long)]
+        fee_bps: Option<u16>,
+        /// Minimum campaign deposit in SOL
+        #[arg(long)]
+        min_deposit: f64,
+        /// 1 = Bronze up to 5 = Diamond
+        #[arg(long, default_value_t = 0)]
+        min_reviewer_rank: u8,
+        #[arg(long, default_value_t = 500)]
+        max_description_len: u16,
+    },
+    /// Open a campaign with the keypair as merchant
+    CreateCampaign {
+        #[arg(long)]
+        platform: Pubkey,
+        /// Category name, its policy sets the minimum deposit and fee
+        #[arg(long)]
+        category: String,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        product_id: String,
+        /// Deposit in SOL, split between the platform fee and reviewer rewards
+        #[arg(long)]
+        deposit: f64,
+        #[arg(long)]
+        reviews_needed: u16,
+        /// Unix timestamp, defaults to now
+        #[arg(long)]
+        start: Option<i64>,
+        #[arg(long, default_value_t = 7)]
+        days: i64,
+    },
+    /// Look up a campaign by merchant and campaign id
+    ShowCampaign {
+        #[arg(long)]
+        merchant: Pubkey,
+        #[arg(long)]
+        id: u64,
+    },
+    /// Change a campaign's display name with the keypair as merchant
+    RenameCampaign {
+        #[arg(long)]
+        campaign: Pubkey,
+        #[arg(long)]
+        name: String,
+    },
+    /// List campaigns, optionally only those of one merchant
+    ListCampaigns {
+        #[arg(long)]
+        merchant: Option<Pubkey>,
+    },
+    /// Submit a review with the keypair as reviewer, creating the reviewer account if needed
+    Review {
+        #[arg(long)]
+        platform: Pubkey,
+        #[arg(long)]
+        campaign: Pubkey,
+        #[arg(long)]
+        description: String,
+        /// Signature of the purchase transaction
+        #[arg(long)]
+        tx_id: String,
+    },
+    /// Approve or flag a review with the keypair as platform admin
+    #[command(group(ArgGroup::new("verdict").required(true).args(["approve", "flag"])))]
+    Moderate {
+        #[arg(long)]
+        platform: Pubkey,
+        #[arg(long)]
+        campaign: Pubkey,
+        #[arg(long)]
+        reviewer: Pubkey,
+        #[arg(long)]
+        approve: bool,
+        /// Flag the review with this reason
+        #[arg(long)]
+        flag: Option<String>,
+    },
+    /// Claim the reward for an approved review with the keypair as reviewer
+    Claim {
+        #[arg(long)]
+        platform: Pubkey,
+        #[arg(long)]
+        campaign: Pubkey,
+        /// Take the reward as REV at the platform's premium instead of SOL
+        #[arg(long)]
+        rev: bool,
+    },
+    /// Refund what's left of a campaign deposit to the merchant
+    Refund {
+        #[arg(long)]
+        platform: Pubkey,
+        #[arg(long)]
+        campaign: Pubkey,
+    },
+    /// Show platform totals and the treasury balance, or withdraw fees as admin
+    Treasury {
+        #[arg(long)]
+        platform: Pubkey,
+        /// Amount in SOL to withdraw, requested for later if the platform has a withdrawal timelock
+        #[arg(long, conflicts_with_all = ["execute", "cancel"])]
+        withdraw: Option<f64>,
+        /// Where withdrawn fees go, defaults to the admin
+        #[arg(long, requires = "withdraw")]
+        destination: Option<Pubkey>,
+        /// Pay out the pending withdrawal once its timelock has passed
+        #[arg(long, conflicts_with = "cancel")]
+        execute: bool,
+        /// Drop the pending withdrawal
+        #[arg(long)]
+        cancel: bool,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(err) = run(cli) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn expand_home(path: String, home: &str) -> String {
+    match path.strip_prefix("~/") {
+        Some(rest) => format!("{home}/{rest}"),
+        None => path,
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let keypair_path = expand_home(cli.keypair, &std::env::var("HOME")?);
+
+    let payer = read_keypair_file(&keypair_path)
+        .map_err(|err| format!("failed to read keypair {keypair_path}: {err}"))?;
+
+    let ctx = Context {
+        rpc: RpcClient::new(cli.url),
+        payer,
+        output: cli.output,
+    };
+
+    match cli.command {
+        Command::InitPlatform { seed, fee_bps } => ctx.init_platform(seed, fee_bps),
+        Command::CreateCategory { platform, name, fee_bps, min_deposit, min_reviewer_rank, max_description_len } => {
+            ctx.create_category(platform, name, fee_bps, min_deposit, min_reviewer_rank, max_description_len)
+        }
+        Command::CreateCampaign { platform, category, name, product_id, deposit, reviews_needed, start, days } => {
+            ctx.create_campaign(platform, category, name, product_id, deposit, reviews_needed, start, days)
+        }
+        Command::ShowCampaign { merchant, id } => ctx.show_campaign(merchant, id),
+        Command::RenameCampaign { campaign, name } => ctx.rename_campaign(campaign, name),
+        Command::ListCampaigns { merchant } => ctx.list_campaigns(merchant),
+        Command::Review { platform, campaign, description, tx_id } => ctx.review(platform, campaign, description, tx_id),
+        Command::Moderate { platform, campaign, reviewer, approve, flag } => {
+            ctx.moderate(platform, campaign, reviewer, approve, flag)
+        }
+        Command::Claim { platform, campaign, rev } => ctx.claim(platform, campaign, rev),
+        Command::Refund { platform, campaign } => ctx.refund(platform, campaign),
+        Command::Treasury { platform, withdraw, destination, execute, cancel } => {
+            ctx.treasury(platform, withdraw, destination, execute, cancel)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    const PLATFORM: &str = "CB9cLPfpZM2Dkjrep4LhiNXCpFa5iXhU3Jjr7TDFR8XF";
+    const CAMPAIGN: &str = "11111111111111111111111111111111";
+    const REVIEWER: &str = "SysvarRent111111111111111111111111111111111";
+
+    fn parse(args: &[&str]) -> std::result::Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("realvue").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn global_options_have_local_defaults() {
+        let cli = parse(&["list-campaigns"]).unwrap();
+
+        assert_eq!(cli.url, "http://127.0.0.1:8899");
+        assert_eq!(cli.keypair, "~/.config/solana/id.json");
+        assert!(matches!(cli.output, OutputFormat::Table));
+        assert!(matches!(cli.command, Command::ListCampaigns { merchant: None }));
+    }
+
+    #[test]
+    fn parses_pubkey_arguments() {
+        let cli = parse(&[
+            "moderate", "--platform", PLATFORM, "--campaign", CAMPAIGN, "--reviewer", REVIEWER, "--approve",
+        ])
+        .unwrap();
+
+        let Command::Moderate { platform, campaign, reviewer, approve, flag } = cli.command else {
+            panic!("expected moderate");
+        };
+        assert_eq!(platform, realvue::ID);
+        assert_eq!(campaign, Pubkey::default());
+        assert_eq!(reviewer.to_string(), REVIEWER);
+        assert!(approve);
+        assert!(flag.is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_pubkeys() {
+        assert!(parse(&["list-campaigns", "--merchant", "not-a-pubkey"]).is_err());
+        assert!(parse(&["refund", "--platform", PLATFORM, "--campaign", "0OIl"]).is_err());
+    }
+
+    #[test]
+    fn moderate_needs_exactly_one_verdict() {
+        let base = ["moderate", "--platform", PLATFORM, "--campaign", CAMPAIGN, "--reviewer", REVIEWER];
+
+        assert!(parse(&base).is_err());
+        assert!(parse(&[&base[..], &["--approve", "--flag", "spam"]].concat()).is_err());
+
+        let cli = parse(&[&base[..], &["--flag", "spam"]].concat()).unwrap();
+        let Command::Moderate { approve, flag, .. } = cli.command else {
+            panic!("expected moderate");
+        };
+        assert!(!approve);
+        assert_eq!(flag.as_deref(), Some("spam"));
+    }
+
+    #[test]
+    fn treasury_destination_requires_withdraw() {
+        assert!(parse(&["treasury", "--platform", PLATFORM, "--destination", REVIEWER]).is_err());
+
+        let cli = parse(&["treasury", "--platform", PLATFORM, "--withdraw", "0.5", "--destination", REVIEWER]).unwrap();
+        let Command::Treasury { withdraw, destination, .. } = cli.command else {
+            panic!("expected treasury");
+        };
+        assert_eq!(withdraw, Some(0.5));
+        assert_eq!(destination.map(|key| key.to_string()).as_deref(), Some(REVIEWER));
+    }
+
+    #[test]
+    fn treasury_takes_one_withdrawal_step_at_a_time() {
+        assert!(parse(&["treasury", "--platform", PLATFORM, "--withdraw", "0.5", "--execute"]).is_err());
+        assert!(parse(&["treasury", "--platform", PLATFORM, "--withdraw", "0.5", "--cancel"]).is_err());
+        assert!(parse(&["treasury", "--platform", PLATFORM, "--execute", "--cancel"]).is_err());
+
+        let cli = parse(&["treasury", "--platform", PLATFORM, "--execute"]).unwrap();
+        let Command::Treasury { withdraw, execute, cancel, .. } = cli.command else {
+            panic!("expected treasury");
+        };
+        assert_eq!(withdraw, None);
+        assert!(execute && !cancel);
+    }
+
+    #[test]
+    fn create_campaign_defaults_to_a_week_from_now() {
+        let cli = parse(&[
+            "create-campaign", "--platform", PLATFORM, "--category", "art", "--name", "Drop", "--product-id", "P1",
+            "--deposit", "1.5", "--reviews-needed", "3",
+        ])
+        .unwrap();
+
+        let Command::CreateCampaign { deposit, reviews_needed, start, days, .. } = cli.command else {
+            panic!("expected create-campaign");
+        };
+        assert_eq!(deposit, 1.5);
+        assert_eq!(reviews_needed, 3);
+        assert_eq!(start, None);
+        assert_eq!(days, 7);
+    }
+
+    #[test]
+    fn expands_home_in_keypair_paths() {
+        assert_eq!(expand_home("~/.config/solana/id.json".to_string(), "/home/me"), "/home/me/.config/solana/id.json");
+        assert_eq!(expand_home("/keys/id.json".to_string(), "/home/me"), "/keys/id.json");
+        assert_eq!(expand_home("~other/id.json".to_string(), "/home/me"), "~other/id.json");
+    }
+}
\ No newline at end of file
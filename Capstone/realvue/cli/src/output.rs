@@ -0,0 +1,56 @@
+use clap::ValueEnum;
+use comfy_table::{presets::UTF8_FULL, Table};
+use serde_json::Value;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+// Prints a single JSON object as a field/value table, or an array of objects with one row each
+pub fn print(format: OutputFormat, value: &Value) {
+    if let OutputFormat::Json = format {
+        println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+        return;
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+
+    match value {
+        Value::Array(rows) => {
+            let Some(Value::Object(first)) = rows.first() else {
+                println!("(none)");
+                return;
+            };
+
+            table.set_header(first.keys());
+            for row in rows {
+                if let Value::Object(row) = row {
+                    table.add_row(row.values().map(cell));
+                }
+            }
+        }
+        Value::Object(fields) => {
+            table.set_header(["field", "value"]);
+            for (key, value) in fields {
+                table.add_row([key.clone(), cell(value)]);
+            }
+        }
+        other => {
+            println!("{}", cell(other));
+            return;
+        }
+    }
+
+    println!("{table}");
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod pda;
+pub mod rpc;
+
+pub use rpc::{RpcAccount, RpcClient, SignatureInfo};
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
\ No newline at end of file
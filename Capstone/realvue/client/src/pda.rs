@@ -0,0 +1,61 @@
+use anchor_lang::prelude::Pubkey;
+
+// Seeds mirror the #[account(seeds = ...)] constraints in the program
+
+pub fn platform(seed: u64, admin: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"realvue", &seed.to_le_bytes(), admin.as_ref()], &realvue::ID).0
+}
+
+pub fn rev_mint(platform: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"rev", platform.as_ref()], &realvue::ID).0
+}
+
+pub fn treasury(platform: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"treasury", platform.as_ref()], &realvue::ID).0
+}
+
+pub fn category(platform: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"category", platform.as_ref(), name.as_bytes()], &realvue::ID).0
+}
+
+pub fn merchant_counter(merchant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"merchant_counter", merchant.as_ref()], &realvue::ID).0
+}
+
+pub fn campaign(merchant: &Pubkey, campaign_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"campaign", merchant.as_ref(), &campaign_id.to_le_bytes()], &realvue::ID).0
+}
+
+// Campaigns created before campaign ids (campaign_id 0) are keyed by name
+pub fn legacy_campaign(name: &str, merchant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"campaign", name.as_bytes(), merchant.as_ref()], &realvue::ID).0
+}
+
+pub fn vault(campaign: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"campaign_vault", campaign.as_ref()], &realvue::ID).0
+}
+
+pub fn merchant_account(platform: &Pubkey, merchant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"merchant", platform.as_ref(), merchant.as_ref()], &realvue::ID).0
+}
+
+pub fn reviewer_account(platform: &Pubkey, reviewer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"reviewer", platform.as_ref(), reviewer.as_ref()], &realvue::ID).0
+}
+
+pub fn review(campaign: &Pubkey, reviewer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[campaign.as_ref(), reviewer.as_ref()], &realvue::ID).0
+}
+
+pub fn purchase_record(campaign: &Pubkey, tx_id: &str) -> Pubkey {
+    let hash = realvue::PurchaseRecord::purchase_hash(campaign, tx_id);
+    Pubkey::find_program_address(&[b"purchase", hash.as_ref()], &realvue::ID).0
+}
+
+pub fn bond_vault(review: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"bond", review.as_ref()], &realvue::ID).0
+}
+
+pub fn attestation(review: &Pubkey, attestor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"attestation", review.as_ref(), attestor.as_ref()], &realvue::ID).0
+}
\ No newline at end of file
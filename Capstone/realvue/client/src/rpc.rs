@@ -0,0 +1,183 @@
+use std::{thread::sleep, time::Duration};
+
+use anchor_lang::prelude::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, signature::Signature, transaction::Transaction};
+
+use crate::Result;
+
+pub struct RpcAccount {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    pub failed: bool,
+}
+
+// Just the JSON-RPC calls the CLI needs, at `confirmed` commitment
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+        let mut response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(&body)?
+            .body_mut()
+            .read_json()?;
+
+        if let Some(error) = response.get("error") {
+            // Preflight failures carry the program logs, those are what the user needs to see
+            let logs = error["data"]["logs"]
+                .as_array()
+                .map(|logs| logs.iter().filter_map(Value::as_str).collect::<Vec<_>>().join("\n"))
+                .unwrap_or_default();
+            return Err(format!("{method} failed: {}\n{logs}", error["message"]).into());
+        }
+
+        Ok(response["result"].take())
+    }
+
+    pub fn get_account(&self, address: &Pubkey) -> Result<Option<RpcAccount>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+
+        match result["value"].is_null() {
+            true => Ok(None),
+            false => Ok(Some(parse_account(&result["value"])?)),
+        }
+    }
+
+    pub fn get_balance(&self, address: &Pubkey) -> Result<u64> {
+        let result = self.call("getBalance", json!([address.to_string(), { "commitment": "confirmed" }]))?;
+
+        result["value"].as_u64().ok_or_else(|| "malformed getBalance response".into())
+    }
+
+    // Program accounts whose data matches every (offset, bytes) filter
+    pub fn get_program_accounts(&self, program_id: &Pubkey, filters: &[(usize, &[u8])]) -> Result<Vec<(Pubkey, RpcAccount)>> {
+        let filters: Vec<Value> = filters
+            .iter()
+            .map(|(offset, bytes)| json!({ "memcmp": { "offset": offset, "bytes": bs58::encode(bytes).into_string() } }))
+            .collect();
+
+        let result = self.call(
+            "getProgramAccounts",
+            json!([program_id.to_string(), { "encoding": "base64", "commitment": "confirmed", "filters": filters }]),
+        )?;
+
+        result
+            .as_array()
+            .ok_or("malformed getProgramAccounts response")?
+            .iter()
+            .map(|entry| {
+                let address = entry["pubkey"].as_str().ok_or("missing pubkey")?.parse()?;
+                Ok((address, parse_account(&entry["account"])?))
+            })
+            .collect()
+    }
+
+    // Newest first, at most `limit`, strictly between `before` and `until` when given
+    pub fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>> {
+        let result = self.call(
+            "getSignaturesForAddress",
+            json!([address.to_string(), { "commitment": "confirmed", "limit": limit, "before": before, "until": until }]),
+        )?;
+
+        result
+            .as_array()
+            .ok_or("malformed getSignaturesForAddress response")?
+            .iter()
+            .map(|entry| {
+                Ok(SignatureInfo {
+                    signature: entry["signature"].as_str().ok_or("missing signature")?.to_string(),
+                    slot: entry["slot"].as_u64().ok_or("missing slot")?,
+                    failed: !entry["err"].is_null(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn get_transaction_logs(&self, signature: &str) -> Result<Vec<String>> {
+        let result = self.call(
+            "getTransaction",
+            json!([signature, { "encoding": "json", "commitment": "confirmed", "maxSupportedTransactionVersion": 0 }]),
+        )?;
+
+        let logs = result["meta"]["logMessages"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default();
+
+        Ok(logs)
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+
+        Ok(result["value"]["blockhash"].as_str().ok_or("malformed getLatestBlockhash response")?.parse()?)
+    }
+
+    pub fn send_and_confirm(&self, transaction: &Transaction) -> Result<Signature> {
+        let encoded = STANDARD.encode(bincode::serialize(transaction)?);
+
+        let result = self.call(
+            "sendTransaction",
+            json!([encoded, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature: Signature = result.as_str().ok_or("malformed sendTransaction response")?.parse()?;
+
+        // Blockhashes expire after ~60s, no point polling longer than that
+        for _ in 0..120 {
+            let result = self.call("getSignatureStatuses", json!([[signature.to_string()]]))?;
+            let status = &result["value"][0];
+
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(format!("transaction {signature} failed: {}", status["err"]).into());
+                }
+
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(signature);
+                }
+            }
+
+            sleep(Duration::from_millis(500));
+        }
+
+        Err(format!("transaction {signature} was not confirmed in time").into())
+    }
+}
+
+fn parse_account(value: &Value) -> Result<RpcAccount> {
+    let data = value["data"][0].as_str().ok_or("missing account data")?;
+
+    Ok(RpcAccount {
+        owner: value["owner"].as_str().ok_or("missing owner")?.parse()?,
+        data: STANDARD.decode(data)?,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use rusqlite::Connection;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Response, Server};
+
+use realvue_client::Result;
+
+use crate::db;
+
+const DEFAULT_LIMIT: u32 = 50;
+const MAX_LIMIT: u32 = 500;
+
+// GET /health
+// GET /campaigns
+// GET /queue?limit=N                      pending reviews across campaigns, oldest first
+// GET /campaigns/<address>/queue?limit=N  pending reviews of one campaign, oldest first
+// GET /replies?limit=N                   merchant replies across campaigns, newest first
+// GET /campaigns/<address>/replies?limit=N
+// GET /reviewers/<wallet>
+pub fn serve(listen: &str, conn: Connection) -> Result<()> {
+    let server = Server::http(listen).map_err(|err| format!("failed to listen on {listen}: {err}"))?;
+
+    for request in server.incoming_requests() {
+        let (status, body) = match request.method() {
+            Method::Get => route(&conn, request.url()).unwrap_or_else(|err| (500, json!({ "error": err.to_string() }))),
+            _ => (405, json!({ "error": "method not allowed" })),
+        };
+
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "application/json").expect("static header"));
+
+        if let Err(err) = request.respond(response) {
+            eprintln!("failed to respond: {err}");
+        }
+    }
+
+    Ok(())
+}
+
+fn route(conn: &Connection, url: &str) -> Result<(u16, Value)> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    let limit = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("limit="))
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_LIMIT);
+
+    let body = match segments.as_slice() {
+        ["health"] => json!({
+            "last_sync": db::get_state(conn, "last_sync")?,
+            "last_signature": db::get_state(conn, "last_signature")?,
+        }),
+        ["campaigns"] => db::campaigns(conn)?,
+        ["queue"] => db::moderation_queue(conn, None, now()?, limit)?,
+        ["campaigns", campaign, "queue"] => db::moderation_queue(conn, Some(campaign), now()?, limit)?,
+        ["replies"] => db::replies(conn, None, limit)?,
+        ["campaigns", campaign, "replies"] => db::replies(conn, Some(campaign), limit)?,
+        ["reviewers", reviewer] => db::reviewer(conn, reviewer)?,
+        _ => return Ok((404, json!({ "error": "not found" }))),
+    };
+
+    Ok((200, body))
+}
+
+fn now() -> Result<i64> {
+    Ok(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs() as i64)
+}
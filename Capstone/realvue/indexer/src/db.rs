@@ -0,0 +1,532 @@
+use realvue::{CompressedReview, ReviewAccount, ReviewCampaign, ReviewReplyUpdated, ReviewerAccount};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde_json::{json, Map, Value};
+
+use realvue_client::Result;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS campaigns (
+    address TEXT PRIMARY KEY,
+    merchant TEXT NOT NULL,
+    name TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    deposit_amount INTEGER NOT NULL,
+    reviews_needed INTEGER NOT NULL,
+    approved_count INTEGER NOT NULL,
+    active INTEGER NOT NULL,
+    cancelled INTEGER NOT NULL,
+    refunded INTEGER NOT NULL,
+    start_time INTEGER NOT NULL,
+    end_time INTEGER NOT NULL,
+    merkle_tree TEXT,
+    grace_ends_at INTEGER NOT NULL DEFAULT 0
+);
+
+-- Review PDAs are keyed by address, compressed reviews by '<merkle_tree>:<leaf_index>'
+CREATE TABLE IF NOT EXISTS reviews (
+    id TEXT PRIMARY KEY,
+    campaign TEXT NOT NULL,
+    reviewer TEXT NOT NULL,
+    description TEXT NOT NULL,
+    tx_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    flagged_reason TEXT NOT NULL,
+    reward_claimed INTEGER NOT NULL,
+    reviewer_rank INTEGER NOT NULL,
+    passing_attestations INTEGER NOT NULL,
+    submitted_at INTEGER NOT NULL,
+    compressed INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS reviews_queue ON reviews (status, campaign, submitted_at);
+
+CREATE TABLE IF NOT EXISTS reviewers (
+    address TEXT PRIMARY KEY,
+    reviewer TEXT NOT NULL,
+    platform TEXT NOT NULL,
+    approved_count INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    total_earned INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS reviewers_by_wallet ON reviewers (reviewer);
+
+-- Built from ReviewReplyUpdated events, closed replies are deleted
+CREATE TABLE IF NOT EXISTS replies (
+    address TEXT PRIMARY KEY,
+    review TEXT NOT NULL,
+    campaign TEXT NOT NULL,
+    merchant TEXT NOT NULL,
+    body TEXT NOT NULL,
+    hidden INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS replies_by_campaign ON replies (campaign, updated_at);
+
+CREATE TABLE IF NOT EXISTS sync_state (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+";
+
+pub fn open(path: &str) -> Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+    conn.execute_batch(SCHEMA)?;
+
+    // Databases from before grace_ends_at was indexed get it appended, the next sync fills it in
+    if conn.prepare("SELECT grace_ends_at FROM campaigns LIMIT 0").is_err() {
+        conn.execute_batch("ALTER TABLE campaigns ADD COLUMN grace_ends_at INTEGER NOT NULL DEFAULT 0")?;
+    }
+
+    Ok(conn)
+}
+
+// A review nobody judged is settled by claim_kill_fee once its cancelled campaign's grace
+// period ends, the kill fee marks its reward claimed
+fn review_status(approved: bool, flagged_reason: &str, reward_claimed: bool) -> &'static str {
+    match (approved, flagged_reason.is_empty(), reward_claimed) {
+        (true, _, _) => "approved",
+        (false, false, _) => "flagged",
+        (false, true, true) => "expired",
+        (false, true, false) => "pending",
+    }
+}
+
+// Account-backed rows are replaced wholesale each pass, so closed accounts drop out
+pub fn clear_account_rows(tx: &Transaction) -> Result<()> {
+    tx.execute_batch("DELETE FROM campaigns; DELETE FROM reviews WHERE compressed = 0; DELETE FROM reviewers;")?;
+    Ok(())
+}
+
+pub fn insert_campaign(tx: &Transaction, address: &str, campaign: &ReviewCampaign) -> Result<()> {
+    tx.execute(
+        "INSERT INTO campaigns VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            address,
+            campaign.merchant.to_string(),
+            campaign.name,
+            campaign.product_id,
+            campaign.deposit_amount as i64,
+            campaign.reviews_needed,
+            campaign.approved_count,
+            campaign.active,
+            campaign.cancelled,
+            campaign.refunded,
+            campaign.start_time,
+            campaign.end_time,
+            campaign.merkle_tree.map(|tree| tree.to_string()),
+            campaign.grace_ends_at,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_review(tx: &Transaction, address: &str, review: &ReviewAccount) -> Result<()> {
+    tx.execute(
+        "INSERT INTO reviews VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, 0)",
+        params![
+            address,
+            review.campaign_id.to_string(),
+            review.reviewer.to_string(),
+            review.description,
+            review.tx_id,
+            review_status(review.approved, &review.flagged_reason, review.reward_claimed),
+            review.flagged_reason,
+            review.reward_claimed,
+            review.reviewer_rank,
+            review.passing_attestations,
+            review.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+// Events arrive oldest first, so the latest leaf contents win
+pub fn upsert_compressed_review(tx: &Transaction, merkle_tree: &str, review: &CompressedReview) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO reviews VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 0, ?10, 1)",
+        params![
+            format!("{merkle_tree}:{}", review.leaf_index),
+            review.campaign_id.to_string(),
+            review.reviewer.to_string(),
+            review.description,
+            review.tx_id,
+            review_status(review.approved, &review.flagged_reason, review.reward_claimed),
+            review.flagged_reason,
+            review.reward_claimed,
+            review.reviewer_rank,
+            review.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn apply_reply_update(tx: &Transaction, update: &ReviewReplyUpdated) -> Result<()> {
+    if update.closed {
+        tx.execute("DELETE FROM replies WHERE address = ?1", [update.reply.to_string()])?;
+        return Ok(());
+    }
+
+    tx.execute(
+        "INSERT OR REPLACE INTO replies VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            update.reply.to_string(),
+            update.review.to_string(),
+            update.campaign.to_string(),
+            update.merchant.to_string(),
+            update.body,
+            update.hidden,
+            update.updated_at,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_reviewer(tx: &Transaction, address: &str, reviewer: &ReviewerAccount) -> Result<()> {
+    tx.execute(
+        "INSERT INTO reviewers VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            address,
+            reviewer.reviewer.to_string(),
+            reviewer.platform.to_string(),
+            reviewer.approved_count,
+            reviewer.rank,
+            reviewer.total_earned as i64,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn get_state(conn: &Connection, key: &str) -> Result<Option<String>> {
+    Ok(conn
+        .query_row("SELECT value FROM sync_state WHERE key = ?1", [key], |row| row.get(0))
+        .optional()?)
+}
+
+pub fn set_state(tx: &Transaction, key: &str, value: &str) -> Result<()> {
+    tx.execute("INSERT OR REPLACE INTO sync_state VALUES (?1, ?2)", [key, value])?;
+    Ok(())
+}
+
+// Pending reviews oldest first, optionally for one campaign. Cancelled campaigns drop out once
+// their grace period ends at `now`, approve_review rejects them from then on. `current_rank` is
+// the reviewer's best rank across platforms now, `reviewer_rank` the one they had when submitting.
+pub fn moderation_queue(conn: &Connection, campaign: Option<&str>, now: i64, limit: u32) -> Result<Value> {
+    query_json(
+        conn,
+        "SELECT r.id, r.campaign, c.name AS campaign_name, r.reviewer, r.reviewer_rank,
+                COALESCE(s.rank, r.reviewer_rank) AS current_rank, r.passing_attestations,
+                r.submitted_at, r.tx_id, r.description, r.compressed
+         FROM reviews r
+         LEFT JOIN campaigns c ON c.address = r.campaign
+         LEFT JOIN (SELECT reviewer, MAX(rank) AS rank FROM reviewers GROUP BY reviewer) s ON s.reviewer = r.reviewer
+         WHERE r.status = 'pending' AND (?1 IS NULL OR r.campaign = ?1)
+           AND NOT (COALESCE(c.cancelled, 0) = 1 AND c.grace_ends_at <= ?3)
+         ORDER BY r.submitted_at ASC, r.id ASC
+         LIMIT ?2",
+        params![campaign, limit, now],
+    )
+}
+
+// Replies newest first so moderators see fresh ones, optionally for one campaign
+pub fn replies(conn: &Connection, campaign: Option<&str>, limit: u32) -> Result<Value> {
+    query_json(
+        conn,
+        "SELECT address, review, campaign, merchant, body, hidden, updated_at
+         FROM replies
+         WHERE ?1 IS NULL OR campaign = ?1
+         ORDER BY updated_at DESC, address ASC
+         LIMIT ?2",
+        params![campaign, limit],
+    )
+}
+
+pub fn campaigns(conn: &Connection) -> Result<Value> {
+    query_json(
+        conn,
+        "SELECT c.address, c.name, c.merchant, c.deposit_amount, c.reviews_needed, c.approved_count,
+                (SELECT COUNT(*) FROM reviews r WHERE r.campaign = c.address AND r.status = 'pending') AS pending,
+                c.active, c.cancelled, c.refunded, c.end_time, c.merkle_tree
+         FROM campaigns c
+         ORDER BY pending DESC, c.end_time ASC",
+        [],
+    )
+}
+
+pub fn reviewer(conn: &Connection, reviewer: &str) -> Result<Value> {
+    query_json(
+        conn,
+        "SELECT address, platform, approved_count, rank, total_earned,
+                (SELECT COUNT(*) FROM reviews WHERE reviewer = ?1 AND status = 'pending') AS pending,
+                (SELECT COUNT(*) FROM reviews WHERE reviewer = ?1 AND status = 'flagged') AS flagged
+         FROM reviewers WHERE reviewer = ?1",
+        [reviewer],
+    )
+}
+
+fn query_json(conn: &Connection, sql: &str, params: impl rusqlite::Params) -> Result<Value> {
+    let mut stmt = conn.prepare(sql)?;
+    let columns: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+
+    let rows = stmt.query_map(params, |row| {
+        let mut object = Map::new();
+        for (i, column) in columns.iter().enumerate() {
+            let value = match row.get_ref(i)? {
+                rusqlite::types::ValueRef::Null => Value::Null,
+                rusqlite::types::ValueRef::Integer(n) => json!(n),
+                rusqlite::types::ValueRef::Real(f) => json!(f),
+                rusqlite::types::ValueRef::Text(text) => json!(String::from_utf8_lossy(text)),
+                rusqlite::types::ValueRef::Blob(_) => Value::Null,
+            };
+            object.insert(column.clone(), value);
+        }
+        Ok(Value::Object(object))
+    })?;
+
+    Ok(Value::Array(rows.collect::<rusqlite::Result<_>>()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator, Space};
+
+    use super::*;
+
+    // Account with every field zeroed, tests fill in what they need
+    fn blank<T: AccountDeserialize + Discriminator + Space>() -> T {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.resize(8 + T::INIT_SPACE, 0);
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    fn review(campaign: Pubkey, reviewer: Pubkey, timestamp: i64) -> ReviewAccount {
+        let mut review = blank::<ReviewAccount>();
+        review.campaign_id = campaign;
+        review.reviewer = reviewer;
+        review.reviewer_rank = 1;
+        review.timestamp = timestamp;
+        review
+    }
+
+    fn reviewer(wallet: Pubkey, rank: u8) -> ReviewerAccount {
+        ReviewerAccount {
+            reviewer: wallet,
+            platform: Pubkey::new_unique(),
+            approved_count: 0,
+            rank,
+            total_earned: 0,
+            badge_rank: 0,
+            rent_debt: 0,
+            bump: 0,
+        }
+    }
+
+    fn reply_update(reply: Pubkey, campaign: Pubkey, body: &str, updated_at: i64, closed: bool) -> ReviewReplyUpdated {
+        ReviewReplyUpdated {
+            reply,
+            review: Pubkey::new_unique(),
+            campaign,
+            merchant: Pubkey::new_unique(),
+            body: body.to_string(),
+            hidden: false,
+            updated_at,
+            closed,
+        }
+    }
+
+    fn ids(rows: &Value, column: &str) -> Vec<String> {
+        rows.as_array().unwrap().iter().map(|row| row[column].as_str().unwrap().to_string()).collect()
+    }
+
+    #[test]
+    fn review_status_follows_approval_then_flag() {
+        assert_eq!(review_status(true, "", false), "approved");
+        assert_eq!(review_status(true, "stale flag", true), "approved");
+        assert_eq!(review_status(false, "spam", false), "flagged");
+        assert_eq!(review_status(false, "spam", true), "flagged");
+        assert_eq!(review_status(false, "", false), "pending");
+        assert_eq!(review_status(false, "", true), "expired");
+    }
+
+    #[test]
+    fn moderation_queue_lists_pending_reviews_oldest_first() {
+        let mut conn = open(":memory:").unwrap();
+        let (campaign, other_campaign) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let wallet = Pubkey::new_unique();
+
+        let tx = conn.transaction().unwrap();
+        let mut campaign_state = blank::<ReviewCampaign>();
+        campaign_state.name = "Drop".to_string();
+        insert_campaign(&tx, &campaign.to_string(), &campaign_state).unwrap();
+
+        insert_review(&tx, "newer", &review(campaign, wallet, 300)).unwrap();
+        insert_review(&tx, "older", &review(campaign, Pubkey::new_unique(), 100)).unwrap();
+        insert_review(&tx, "other", &review(other_campaign, Pubkey::new_unique(), 200)).unwrap();
+
+        let mut approved = review(campaign, Pubkey::new_unique(), 50);
+        approved.approved = true;
+        insert_review(&tx, "approved", &approved).unwrap();
+
+        let mut flagged = review(campaign, Pubkey::new_unique(), 60);
+        flagged.flagged_reason = "spam".to_string();
+        insert_review(&tx, "flagged", &flagged).unwrap();
+
+        // Current rank is the reviewer's best across platforms
+        insert_reviewer(&tx, "reviewer-a", &reviewer(wallet, 2)).unwrap();
+        insert_reviewer(&tx, "reviewer-b", &reviewer(wallet, 4)).unwrap();
+        tx.commit().unwrap();
+
+        let queue = moderation_queue(&conn, None, 0, 10).unwrap();
+        assert_eq!(ids(&queue, "id"), ["older", "other", "newer"]);
+
+        let queue = moderation_queue(&conn, Some(&campaign.to_string()), 0, 10).unwrap();
+        assert_eq!(ids(&queue, "id"), ["older", "newer"]);
+        assert_eq!(queue[0]["campaign_name"], "Drop");
+        assert_eq!(queue[0]["current_rank"], 1);
+        assert_eq!(queue[1]["reviewer_rank"], 1);
+        assert_eq!(queue[1]["current_rank"], 4);
+
+        let queue = moderation_queue(&conn, None, 0, 1).unwrap();
+        assert_eq!(ids(&queue, "id"), ["older"]);
+    }
+
+    #[test]
+    fn moderation_queue_skips_settled_reviews_and_ended_grace_periods() {
+        let mut conn = open(":memory:").unwrap();
+        let (cancelled, killed) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let tx = conn.transaction().unwrap();
+        let mut campaign_state = blank::<ReviewCampaign>();
+        campaign_state.cancelled = true;
+        campaign_state.grace_ends_at = 500;
+        insert_campaign(&tx, &cancelled.to_string(), &campaign_state).unwrap();
+
+        campaign_state.grace_ends_at = 200;
+        insert_campaign(&tx, &killed.to_string(), &campaign_state).unwrap();
+
+        insert_review(&tx, "in-grace", &review(cancelled, Pubkey::new_unique(), 100)).unwrap();
+        insert_review(&tx, "grace-ended", &review(killed, Pubkey::new_unique(), 100)).unwrap();
+
+        // Paid a kill fee, nothing left to moderate
+        let mut settled = review(cancelled, Pubkey::new_unique(), 50);
+        settled.reward_claimed = true;
+        insert_review(&tx, "settled", &settled).unwrap();
+        tx.commit().unwrap();
+
+        let queue = moderation_queue(&conn, None, 300, 10).unwrap();
+        assert_eq!(ids(&queue, "id"), ["in-grace"]);
+
+        let queue = moderation_queue(&conn, None, 500, 10).unwrap();
+        assert!(queue.as_array().unwrap().is_empty());
+
+        // Settled reviews don't count as pending anywhere else either
+        let all = campaigns(&conn).unwrap();
+        let pending: Vec<i64> = all.as_array().unwrap().iter().map(|row| row["pending"].as_i64().unwrap()).collect();
+        assert_eq!(pending, [1, 1]);
+    }
+
+    #[test]
+    fn opening_adds_grace_ends_at_to_older_databases() {
+        let path = std::env::temp_dir().join(format!("realvue-index-{}.sqlite", Pubkey::new_unique()));
+        let path = path.to_str().unwrap();
+
+        let old = Connection::open(path).unwrap();
+        old.execute_batch(&SCHEMA.replace(",\n    grace_ends_at INTEGER NOT NULL DEFAULT 0", "")).unwrap();
+        drop(old);
+
+        let mut conn = open(path).unwrap();
+        let tx = conn.transaction().unwrap();
+        insert_campaign(&tx, "campaign", &blank::<ReviewCampaign>()).unwrap();
+        tx.commit().unwrap();
+
+        let grace_ends_at: i64 = conn.query_row("SELECT grace_ends_at FROM campaigns", [], |row| row.get(0)).unwrap();
+        assert_eq!(grace_ends_at, 0);
+
+        drop(conn);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn clearing_account_rows_keeps_compressed_reviews() {
+        let mut conn = open(":memory:").unwrap();
+        let campaign = Pubkey::new_unique();
+        let tree = Pubkey::new_unique();
+
+        let mut leaf = CompressedReview {
+            campaign_id: campaign,
+            reviewer: Pubkey::new_unique(),
+            description: "Great".to_string(),
+            approved: false,
+            reward_claimed: false,
+            flagged_reason: String::new(),
+            tx_id: "tx".to_string(),
+            reviewer_rank: 1,
+            timestamp: 10,
+            leaf_index: 0,
+        };
+
+        let tx = conn.transaction().unwrap();
+        insert_review(&tx, "pda", &review(campaign, Pubkey::new_unique(), 5)).unwrap();
+        upsert_compressed_review(&tx, &tree.to_string(), &leaf).unwrap();
+
+        // A later event for the same leaf replaces it
+        leaf.approved = true;
+        upsert_compressed_review(&tx, &tree.to_string(), &leaf).unwrap();
+
+        clear_account_rows(&tx).unwrap();
+        tx.commit().unwrap();
+
+        let (id, status): (String, String) = conn
+            .query_row("SELECT id, status FROM reviews", [], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!(id, format!("{tree}:0"));
+        assert_eq!(status, "approved");
+    }
+
+    #[test]
+    fn reply_updates_insert_replace_and_close() {
+        let mut conn = open(":memory:").unwrap();
+        let campaign = Pubkey::new_unique();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let tx = conn.transaction().unwrap();
+        apply_reply_update(&tx, &reply_update(first, campaign, "Thanks", 100, false)).unwrap();
+        apply_reply_update(&tx, &reply_update(second, Pubkey::new_unique(), "Noted", 150, false)).unwrap();
+        apply_reply_update(&tx, &reply_update(first, campaign, "Thanks, fixed", 200, false)).unwrap();
+        tx.commit().unwrap();
+
+        // Newest first, an edit replaces the row instead of adding one
+        let all = replies(&conn, None, 10).unwrap();
+        assert_eq!(ids(&all, "address"), [first.to_string(), second.to_string()]);
+        assert_eq!(all[0]["body"], "Thanks, fixed");
+        assert_eq!(all[0]["updated_at"], 200);
+
+        let filtered = replies(&conn, Some(&campaign.to_string()), 10).unwrap();
+        assert_eq!(ids(&filtered, "address"), [first.to_string()]);
+
+        let tx = conn.transaction().unwrap();
+        apply_reply_update(&tx, &reply_update(first, campaign, "", 300, true)).unwrap();
+        // Closing a reply that was never indexed is a no-op
+        apply_reply_update(&tx, &reply_update(Pubkey::new_unique(), campaign, "", 300, true)).unwrap();
+        tx.commit().unwrap();
+
+        let all = replies(&conn, None, 10).unwrap();
+        assert_eq!(ids(&all, "address"), [second.to_string()]);
+    }
+
+    #[test]
+    fn sync_state_round_trips() {
+        let mut conn = open(":memory:").unwrap();
+        assert_eq!(get_state(&conn, "last_signature").unwrap(), None);
+
+        let tx = conn.transaction().unwrap();
+        set_state(&tx, "last_signature", "sig1").unwrap();
+        set_state(&tx, "last_signature", "sig2").unwrap();
+        tx.commit().unwrap();
+
+        assert_eq!(get_state(&conn, "last_signature").unwrap().as_deref(), Some("sig2"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+mod api;
+mod db;
+mod sync;
+
+use std::{thread, time::Duration};
+
+use clap::Parser;
+use realvue_client::{Result, RpcClient};
+
+#[derive(Parser)]
+#[command(name = "realvue-indexer", version, about = "Index realvue into SQLite and serve the moderation queue")]
+struct Args {
+    /// JSON-RPC endpoint to follow, defaults to a local validator
+    #[arg(long, short = 'u', env = "REALVUE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// SQLite database file, created if missing
+    #[arg(long, env = "REALVUE_INDEX_DB", default_value = "realvue-index.sqlite")]
+    db: String,
+
+    /// Address the HTTP API listens on
+    #[arg(long, env = "REALVUE_INDEX_LISTEN", default_value = "127.0.0.1:3000")]
+    listen: String,
+
+    /// Seconds between sync passes
+    #[arg(long, default_value_t = 5)]
+    poll_interval: u64,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    if let Err(err) = run(args) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    // The API gets its own connection, WAL lets it read while the sync loop writes
+    let api_conn = db::open(&args.db)?;
+    let listen = args.listen.clone();
+    thread::spawn(move || {
+        if let Err(err) = api::serve(&listen, api_conn) {
+            eprintln!("api stopped: {err}");
+            std::process::exit(1);
+        }
+    });
+
+    let mut conn = db::open(&args.db)?;
+    let rpc = RpcClient::new(args.url);
+
+    eprintln!("serving the moderation queue on http://{}", args.listen);
+
+    loop {
+        // A failed pass is retried on the next tick, the log cursor only moves on success
+        match sync::sync_once(&rpc, &mut conn) {
+            Ok(stats) => eprintln!(
+                "synced {} campaigns, {} reviews, {} reviewers, {} transactions",
+                stats.campaigns, stats.reviews, stats.reviewers, stats.transactions
+            ),
+            Err(err) => eprintln!("sync failed: {err}"),
+        }
+
+        thread::sleep(Duration::from_secs(args.poll_interval));
+    }
+}
\ No newline at end of file
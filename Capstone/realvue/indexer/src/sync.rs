@@ -0,0 +1,272 @@
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use realvue::{CompressedReviewUpdated, ReviewAccount, ReviewCampaign, ReviewReplyUpdated, ReviewerAccount};
+use realvue_client::{Result, RpcClient};
+use rusqlite::Connection;
+
+use crate::db;
+
+const LAST_SIGNATURE: &str = "last_signature";
+const LAST_SYNC: &str = "last_sync";
+
+// getSignaturesForAddress caps a page at 1000
+const SIGNATURE_PAGE: usize = 1000;
+
+#[derive(Default)]
+pub struct SyncStats {
+    pub campaigns: usize,
+    pub reviews: usize,
+    pub reviewers: usize,
+    pub transactions: usize,
+}
+
+pub fn sync_once(rpc: &RpcClient, conn: &mut Connection) -> Result<SyncStats> {
+    let mut stats = SyncStats::default();
+
+    // Fetch everything before touching the database so a failed pass leaves it as it was
+    let campaigns = rpc.get_program_accounts(&realvue::ID, &[(0, ReviewCampaign::DISCRIMINATOR)])?;
+    let reviews = rpc.get_program_accounts(&realvue::ID, &[(0, ReviewAccount::DISCRIMINATOR)])?;
+    let reviewers = rpc.get_program_accounts(&realvue::ID, &[(0, ReviewerAccount::DISCRIMINATOR)])?;
+    let transactions = new_transactions(rpc, db::get_state(conn, LAST_SIGNATURE)?)?;
+
+    let tx = conn.transaction()?;
+
+    db::clear_account_rows(&tx)?;
+
+    // Accounts still on an old layout fail to decode until they are migrated, skip them
+    for (address, account) in campaigns {
+        if let Ok(campaign) = ReviewCampaign::try_deserialize(&mut account.data.as_slice()) {
+            db::insert_campaign(&tx, &address.to_string(), &campaign)?;
+            stats.campaigns += 1;
+        }
+    }
+
+    for (address, account) in reviews {
+        if let Ok(review) = ReviewAccount::try_deserialize(&mut account.data.as_slice()) {
+            db::insert_review(&tx, &address.to_string(), &review)?;
+            stats.reviews += 1;
+        }
+    }
+
+    for (address, account) in reviewers {
+        if let Ok(reviewer) = ReviewerAccount::try_deserialize(&mut account.data.as_slice()) {
+            db::insert_reviewer(&tx, &address.to_string(), &reviewer)?;
+            stats.reviewers += 1;
+        }
+    }
+
+    // Compressed reviews only exist in the logs, replies are followed through their events
+    for (signature, logs) in &transactions {
+        for event in program_events(logs) {
+            if let Some(update) = decode_event::<CompressedReviewUpdated>(&event) {
+                db::upsert_compressed_review(&tx, &update.merkle_tree.to_string(), &update.review)?;
+            } else if let Some(update) = decode_event::<ReviewReplyUpdated>(&event) {
+                db::apply_reply_update(&tx, &update)?;
+            }
+        }
+
+        db::set_state(&tx, LAST_SIGNATURE, signature)?;
+        stats.transactions += 1;
+    }
+
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+    db::set_state(&tx, LAST_SYNC, &now.to_string())?;
+
+    tx.commit()?;
+
+    Ok(stats)
+}
+
+// Successful program transactions after `until`, oldest first, with their logs
+fn new_transactions(rpc: &RpcClient, until: Option<String>) -> Result<Vec<(String, Vec<String>)>> {
+    let mut signatures = Vec::new();
+    let mut before: Option<String> = None;
+
+    loop {
+        let page = rpc.get_signatures_for_address(&realvue::ID, before.as_deref(), until.as_deref(), SIGNATURE_PAGE)?;
+        let done = page.len() < SIGNATURE_PAGE;
+
+        before = page.last().map(|info| info.signature.clone());
+        signatures.extend(page);
+
+        if done || before.is_none() {
+            break;
+        }
+    }
+
+    // Oldest first, sorted by slot in case pages overlapped a slot boundary
+    signatures.reverse();
+    signatures.sort_by_key(|info| info.slot);
+
+    signatures
+        .into_iter()
+        .filter(|info| !info.failed)
+        .map(|info| {
+            let logs = rpc.get_transaction_logs(&info.signature)?;
+            Ok((info.signature, logs))
+        })
+        .collect()
+}
+
+// `Program data:` payloads logged by realvue itself, not by programs it invokes
+fn program_events(logs: &[String]) -> Vec<Vec<u8>> {
+    let program_id = realvue::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if stack.last() == Some(&program_id.as_str()) {
+                if let Ok(bytes) = STANDARD.decode(data) {
+                    events.push(bytes);
+                }
+            }
+        } else if rest.contains(" invoke [") {
+            stack.push(rest.split(' ').next().unwrap_or_default());
+        } else if rest.ends_with(" success") || rest.contains(" failed") {
+            stack.pop();
+        }
+    }
+
+    events
+}
+
+fn decode_event<T: AnchorDeserialize + Discriminator>(data: &[u8]) -> Option<T> {
+    let payload = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut &payload[..]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{prelude::Pubkey, Event};
+    use realvue::CompressedReview;
+
+    use super::*;
+
+    fn reply_event(closed: bool) -> ReviewReplyUpdated {
+        ReviewReplyUpdated {
+            reply: Pubkey::new_unique(),
+            review: Pubkey::new_unique(),
+            campaign: Pubkey::new_unique(),
+            merchant: Pubkey::new_unique(),
+            body: "Thanks".to_string(),
+            hidden: false,
+            updated_at: 42,
+            closed,
+        }
+    }
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn decodes_events_by_discriminator() {
+        let event = reply_event(true);
+        let data = event.data();
+
+        let decoded = decode_event::<ReviewReplyUpdated>(&data).unwrap();
+        assert_eq!(decoded.reply, event.reply);
+        assert_eq!(decoded.body, "Thanks");
+        assert!(decoded.closed);
+
+        // Another event's discriminator or a truncated payload doesn't decode
+        assert!(decode_event::<CompressedReviewUpdated>(&data).is_none());
+        assert!(decode_event::<ReviewReplyUpdated>(&data[..data.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn decodes_compressed_review_events() {
+        let event = CompressedReviewUpdated {
+            merkle_tree: Pubkey::new_unique(),
+            review: CompressedReview {
+                campaign_id: Pubkey::new_unique(),
+                reviewer: Pubkey::new_unique(),
+                description: "Great".to_string(),
+                approved: true,
+                reward_claimed: false,
+                flagged_reason: String::new(),
+                tx_id: "tx".to_string(),
+                reviewer_rank: 2,
+                timestamp: 7,
+                leaf_index: 3,
+            },
+        };
+
+        let decoded = decode_event::<CompressedReviewUpdated>(&event.data()).unwrap();
+        assert_eq!(decoded.merkle_tree, event.merkle_tree);
+        assert_eq!(decoded.review.leaf_index, 3);
+        assert!(decoded.review.approved);
+    }
+
+    #[test]
+    fn keeps_only_data_logged_by_realvue() {
+        let program = realvue::ID.to_string();
+        let own = STANDARD.encode(reply_event(false).data());
+        let inner = STANDARD.encode([1, 2, 3]);
+
+        let events = program_events(&logs(&[
+            &format!("Program {program} invoke [1]"),
+            "Program log: Instruction: ReplyToReview",
+            "Program 11111111111111111111111111111111 invoke [2]",
+            &format!("Program data: {inner}"),
+            "Program 11111111111111111111111111111111 success",
+            &format!("Program data: {own}"),
+            "Program data: not base64!",
+            &format!("Program {program} consumed 5000 of 200000 compute units"),
+            &format!("Program {program} success"),
+            &format!("Program data: {own}"),
+        ]));
+
+        assert_eq!(events, vec![STANDARD.decode(&own).unwrap()]);
+    }
+
+    #[test]
+    fn failed_inner_calls_pop_the_stack() {
+        let program = realvue::ID.to_string();
+        let own = STANDARD.encode([9, 9]);
+
+        let events = program_events(&logs(&[
+            &format!("Program {program} invoke [1]"),
+            "Program cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK invoke [2]",
+            "Program cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK failed: custom program error: 0x1",
+            &format!("Program data: {own}"),
+        ]));
+
+        assert_eq!(events, vec![vec![9, 9]]);
+    }
+
+    // Needs a validator with realvue deployed, e.g. after `anchor test --detach`:
+    // REALVUE_RPC_URL=http://127.0.0.1:8899 cargo test -p realvue-indexer -- --ignored
+    #[test]
+    #[ignore = "needs solana-test-validator with realvue deployed"]
+    fn syncs_from_a_local_validator() {
+        let url = std::env::var("REALVUE_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+        let rpc = RpcClient::new(url);
+        let mut conn = db::open(":memory:").unwrap();
+
+        let first = sync_once(&rpc, &mut conn).unwrap();
+
+        let count = |table: &str| -> usize {
+            let rows: i64 = conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0)).unwrap();
+            rows as usize
+        };
+        assert_eq!(count("campaigns"), first.campaigns);
+        assert_eq!(count("reviewers"), first.reviewers);
+        assert!(count("reviews") >= first.reviews);
+        assert!(db::get_state(&conn, LAST_SYNC).unwrap().is_some());
+
+        if first.transactions > 0 {
+            assert!(db::get_state(&conn, LAST_SIGNATURE).unwrap().is_some());
+        }
+
+        // The log cursor moved, so an immediate second pass has nothing new to replay
+        let second = sync_once(&rpc, &mut conn).unwrap();
+        assert_eq!(second.campaigns, first.campaigns);
+        assert_eq!(second.transactions, 0);
+    }
+}
\ No newline at end of file
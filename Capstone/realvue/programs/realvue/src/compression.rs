@@ -0,0 +1,92 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+        pubkey,
+    },
+};
+
+// SPL account-compression and its noop log wrapper. The published crates still pin
+// an older anchor, so the three instructions used here are built by hand.
+pub const SPL_ACCOUNT_COMPRESSION_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const SPL_NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+// Anchor discriminators, sha256("global:<name>")[..8]
+const INIT_EMPTY_MERKLE_TREE: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+const APPEND: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+const REPLACE_LEAF: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+pub struct CompressionAccounts<'a, 'info> {
+    pub compression_program: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub noop_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CompressionAccounts<'a, 'info> {
+    pub fn init_empty_merkle_tree(
+        &self,
+        max_depth: u32,
+        max_buffer_size: u32,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let mut data = INIT_EMPTY_MERKLE_TREE.to_vec();
+        data.extend_from_slice(&max_depth.to_le_bytes());
+        data.extend_from_slice(&max_buffer_size.to_le_bytes());
+
+        self.invoke(data, &[], signer_seeds)
+    }
+
+    pub fn append(&self, leaf: [u8; 32], signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let mut data = APPEND.to_vec();
+        data.extend_from_slice(&leaf);
+
+        self.invoke(data, &[], signer_seeds)
+    }
+
+    // The proof is passed as remaining accounts, one node per account, leaf to root
+    pub fn replace_leaf(
+        &self,
+        root: [u8; 32],
+        previous_leaf: [u8; 32],
+        new_leaf: [u8; 32],
+        index: u32,
+        proof: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let mut data = REPLACE_LEAF.to_vec();
+        data.extend_from_slice(&root);
+        data.extend_from_slice(&previous_leaf);
+        data.extend_from_slice(&new_leaf);
+        data.extend_from_slice(&index.to_le_bytes());
+
+        self.invoke(data, proof, signer_seeds)
+    }
+
+    fn invoke(&self, data: Vec<u8>, proof: &[AccountInfo<'info>], signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let mut accounts = vec![
+            AccountMeta::new(self.merkle_tree.key(), false),
+            AccountMeta::new_readonly(self.authority.key(), true),
+            AccountMeta::new_readonly(self.noop_program.key(), false),
+        ];
+        accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+        let mut account_infos = vec![
+            self.merkle_tree.clone(),
+            self.authority.clone(),
+            self.noop_program.clone(),
+        ];
+        account_infos.extend(proof.iter().cloned());
+
+        let instruction = Instruction {
+            program_id: self.compression_program.key(),
+            accounts,
+            data,
+        };
+
+        invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
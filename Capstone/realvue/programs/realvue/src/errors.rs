@@ -68,7 +68,7 @@ pub enum PlatformError {
     #[msg("Review not found")]
     ReviewNotFound, 
     
-    #[msg("Review description too long. Maximum 500 characters")]
+    #[msg("Review description too long for the campaign category")]
     ReviewDescriptionTooLong, 
 
     #[msg("Review description too long. Maximum 64 characters")]
@@ -115,4 +115,172 @@ pub enum PlatformError {
     
     #[msg("Fee calculation error")]
     FeeCalculationError, 
+
+    #[msg("Fee tiers must be sorted by volume and within the fee limit")]
+    InvalidFeeTiers,
+
+    #[msg("Invalid referral share. Maximum 10000 basis points")]
+    InvalidReferralShare,
+
+    #[msg("Merchant cannot refer their own campaign")]
+    SelfReferral,
+
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards,
+
+    #[msg("Withdrawal amount must be greater than zero")]
+    InvalidWithdrawalAmount,
+
+    #[msg("Treasury withdrawals are timelocked, request a withdrawal first")]
+    WithdrawalTimelocked,
+
+    #[msg("A treasury withdrawal is already pending")]
+    WithdrawalAlreadyPending,
+
+    #[msg("No pending treasury withdrawal")]
+    NoPendingWithdrawal,
+
+    #[msg("Withdrawal timelock has not elapsed yet")]
+    WithdrawalNotReady,
+
+    #[msg("Destination does not match the pending withdrawal")]
+    InvalidWithdrawalDestination,
+
+    #[msg("Invalid withdrawal timelock")]
+    InvalidTimelock,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Campaign does not vest rewards")]
+    VestingNotEnabled,
+
+    #[msg("Nothing has vested yet")]
+    NothingVested,
+
+    #[msg("Clawback window has closed")]
+    ClawbackWindowClosed,
+
+    #[msg("Campaign still holds vesting rewards")]
+    VestingInProgress,
+
+    #[msg("Too many trusted platforms")]
+    TooManyTrustedPlatforms,
+
+    #[msg("Source platform is not trusted by this platform")]
+    UntrustedPlatform,
+
+    #[msg("Account version is not supported, migrate it first")]
+    UnsupportedAccountVersion,
+
+    #[msg("Too many attestors")]
+    TooManyAttestors,
+
+    #[msg("Attestor is listed more than once")]
+    DuplicateAttestor,
+
+    #[msg("Attestation threshold cannot exceed the number of attestors")]
+    InvalidAttestationThreshold,
+
+    #[msg("Attestor is not registered on this platform")]
+    UnauthorizedAttestor,
+
+    #[msg("Attestation score must be between 0 and 100")]
+    InvalidAttestationScore,
+
+    #[msg("Missing or invalid ed25519 attestation signature")]
+    InvalidAttestation,
+
+    #[msg("Review does not have enough passing attestations")]
+    AttestationRequired,
+
+    #[msg("Rank badge already matches the reviewer rank")]
+    BadgeUpToDate,
+
+    #[msg("Campaign has been cancelled")]
+    CampaignCancelled,
+
+    #[msg("Campaign has not been cancelled")]
+    CampaignNotCancelled,
+
+    #[msg("Cancellation grace period has not ended")]
+    GracePeriodActive,
+
+    #[msg("Invalid cancellation policy")]
+    InvalidCancellationPolicy,
+
+    #[msg("Review is not pending")]
+    ReviewNotPending,
+
+    #[msg("Campaign has pending reviews, cancel it instead")]
+    ReviewsPending,
+
+    #[msg("Campaign already stores reviews in a merkle tree")]
+    CampaignCompressed,
+
+    #[msg("Campaign does not store reviews in a merkle tree")]
+    CampaignNotCompressed,
+
+    #[msg("Review bonds and vesting are not supported for compressed campaigns")]
+    CompressionUnsupported,
+
+    #[msg("Merkle tree does not belong to this campaign")]
+    InvalidMerkleTree,
+
+    #[msg("Compressed review does not belong to this campaign")]
+    InvalidCompressedReview,
+
+    #[msg("Category name must be 1 to 32 characters")]
+    InvalidCategoryName,
+
+    #[msg("Invalid category policy")]
+    InvalidCategoryPolicy,
+
+    #[msg("Category is not accepting new campaigns")]
+    CategoryInactive,
+
+    #[msg("Category account does not match the campaign")]
+    InvalidCategory,
+
+    #[msg("Reviewer rank is below the category minimum")]
+    ReviewerRankTooLow,
+
+    #[msg("Purchase transaction already backs another review")]
+    DuplicatePurchaseProof,
+
+    #[msg("Reply must be between 1 and 280 characters")]
+    InvalidReplyLength,
+
+    #[msg("Payer is not the platform's review sponsor")]
+    UnauthorizedSponsor,
+
+    #[msg("Rent budget must be greater than zero")]
+    InvalidRentBudget,
+
+    #[msg("Campaign name must be between 1 and 32 characters")]
+    InvalidCampaignName,
+
+    #[msg("Campaigns created before campaign ids are keyed by name and can't be renamed")]
+    CampaignNameImmutable,
+
+    #[msg("REV payouts are disabled or missing their accounts")]
+    RevPayoutUnavailable,
+
+    #[msg("REV payout premium can be at most 10000 basis points")]
+    InvalidRevPremium,
+
+    #[msg("Reviews passed to the migration don't match the campaign")]
+    MigrationReviewMismatch,
+
+    #[msg("Approved reviews still have unclaimed rewards")]
+    RewardsUnclaimed,
+
+    #[msg("Reviewer owes sponsored rent, pass the review sponsor to repay it")]
+    SponsorRequired,
+
+    #[msg("Reviewer still owes sponsored rent")]
+    RentDebtOutstanding,
+
+    #[msg("Platform is not the one the campaign was created under")]
+    CampaignPlatformMismatch,
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{CompressedReview, ReviewReply};
+
+#[event]
+pub struct CompressedReviewUpdated {
+    pub merkle_tree: Pubkey,
+    pub review: CompressedReview, // new leaf contents, hashes to the leaf at review.leaf_index
+}
+
+// Emitted on every reply change, `closed` is set when the merchant deletes it
+#[event]
+pub struct ReviewReplyUpdated {
+    pub reply: Pubkey,
+    pub review: Pubkey,
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub body: String,
+    pub hidden: bool,
+    pub updated_at: i64,
+    pub closed: bool,
+}
+
+impl ReviewReplyUpdated {
+    pub fn from_reply(address: Pubkey, reply: &ReviewReply, closed: bool) -> Self {
+        Self {
+            reply: address,
+            review: reply.review,
+            campaign: reply.campaign,
+            merchant: reply.merchant,
+            body: reply.body.clone(),
+            hidden: reply.hidden,
+            updated_at: reply.updated_at,
+            closed,
+        }
+    }
+}
\ No newline at end of file
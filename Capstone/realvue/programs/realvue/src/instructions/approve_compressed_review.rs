@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    compression::{CompressionAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    errors::PlatformError,
+    CompressedReview, CompressedReviewUpdated, PlatformConfig, ReviewCampaign, ReviewerAccount,
+    PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION,
+};
+
+#[derive(Accounts)]
+#[instruction(review: CompressedReview)]
+pub struct ApproveCompressedReview<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), review.reviewer.as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        mut,
+        has_one = admin, // Ensure only the platform admin can approve
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    /// CHECK: must be the campaign's tree, checked in the handler
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program, used by account-compression to log changes
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveCompressedReview<'info> {
+    // `review` is the current leaf contents, the compression program rejects the
+    // replacement unless it hashes to the leaf proven by `root` and the proof accounts
+    pub fn approve_compressed_review(
+        &mut self,
+        review: CompressedReview,
+        root: [u8; 32],
+        approved: bool,
+        flagged_reason: Option<String>,
+        proof: &[AccountInfo<'info>],
+    ) -> Result<()> {
+
+        require!(
+            self.platform.is_active,
+            PlatformError::PlatformInactive
+        );
+
+        require!(
+            self.campaign.merkle_tree == Some(self.merkle_tree.key()),
+            PlatformError::InvalidMerkleTree
+        );
+
+        require!(
+            review.campaign_id == self.campaign.key(),
+            PlatformError::InvalidCompressedReview
+        );
+
+        require!(
+            review.is_pending(),
+            PlatformError::ReviewAlreadyApproved
+        );
+
+        // Cancelled campaigns can still be moderated until the grace period ends
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.campaign.active
+                || (self.campaign.cancelled && current_time < self.campaign.grace_ends_at),
+            PlatformError::CampaignNotActive
+        );
+
+        // Attestations are keyed on review PDAs, compressed reviews can't collect them
+        if approved {
+            require!(
+                self.platform.attestation_threshold == 0,
+                PlatformError::AttestationRequired
+            );
+        }
+
+        // Same checks as validate_review_authenticity in approve_review
+        let is_valid = !review.tx_id.is_empty() && review.reviewer != self.campaign.merchant;
+
+        let previous_leaf = review.leaf_hash()?;
+        let mut updated = review;
+
+        if approved && is_valid {
+            updated.approved = true;
+
+            self.campaign.approved_count = self.campaign.approved_count
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            self.reviewer_account.approved_count = self.reviewer_account.approved_count
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            self.reviewer_account.rank = ReviewerAccount::rank_for(self.reviewer_account.approved_count);
+
+            self.platform.total_reviews = self.platform.total_reviews
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        } else {
+            updated.flagged_reason = flagged_reason
+                .unwrap_or_else(|| "Review verification failed".to_string());
+
+            require!(
+                updated.flagged_reason.len() <= 64,
+                PlatformError::ReasonTooLong
+            );
+        }
+
+        self.campaign.pending_reviews = self.campaign.pending_reviews.saturating_sub(1);
+
+        let [first_seed, second_seed] = self.campaign.address_seeds();
+        let seeds = &[
+            &b"campaign"[..],
+            first_seed.as_ref(),
+            second_seed.as_ref(),
+            &[self.campaign.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let compression = CompressionAccounts {
+            compression_program: &self.compression_program.to_account_info(),
+            merkle_tree: &self.merkle_tree.to_account_info(),
+            authority: &self.campaign.to_account_info(),
+            noop_program: &self.noop_program.to_account_info(),
+        };
+
+        compression.replace_leaf(
+            root,
+            previous_leaf,
+            updated.leaf_hash()?,
+            updated.leaf_index,
+            proof,
+            signer_seeds,
+        )?;
+
+        emit!(CompressedReviewUpdated {
+            merkle_tree: self.merkle_tree.key(),
+            review: updated,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
 
-use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount};
+use crate::{errors::PlatformError, Attestation, BondSlashTarget, PlatformConfig, PurchaseRecord, ReviewAccount, ReviewCampaign, ReviewerAccount, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
 
 #[derive(Accounts)]
 pub struct ApproveReview<'info> {
@@ -11,19 +11,22 @@ pub struct ApproveReview<'info> {
         mut,
         seeds = [campaign.key().as_ref(), review_account.reviewer.key().as_ref()],
         bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub review_account: Account<'info, ReviewAccount>,
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign.name.as_str().as_bytes(), campaign.merchant.key().as_ref()],
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
         bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
     #[account(
         mut,
-        seeds = [b"reviewer", reviewer_account.reviewer.key().as_ref()],
+        seeds = [b"reviewer", platform.key().as_ref(), review_account.reviewer.key().as_ref()],
         bump = reviewer_account.bump
     )]
     pub reviewer_account: Account<'info, ReviewerAccount>,
@@ -33,14 +36,57 @@ pub struct ApproveReview<'info> {
         has_one = admin, // Ensure only the platform admin can approve
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
+    #[account(
+        mut,
+        seeds = [b"bond", review_account.key().as_ref()],
+        bump = review_account.bond_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = review_account.reviewer
+    )]
+    pub reviewer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = campaign.merchant
+    )]
+    pub merchant: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", platform.key().as_ref()],
+        bump = platform.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    // Reviews made before purchase records existed claim theirs here, so a reused tx_id still surfaces
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"purchase", PurchaseRecord::purchase_hash(&campaign.key(), &review_account.tx_id).as_ref()],
+        bump,
+        space = 8 + PurchaseRecord::INIT_SPACE
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ApproveReview<'info> {
-    pub fn approve_review(&mut self, approved: bool, flagged_reason: Option<String>) -> Result<()> {
+    pub fn approve_review(
+        &mut self,
+        approved: bool,
+        flagged_reason: Option<String>,
+        bumps: &ApproveReviewBumps,
+        attestations: &[AccountInfo<'info>],
+    ) -> Result<()> {
         
         require!(
             self.platform.is_active,
@@ -52,13 +98,42 @@ impl<'info> ApproveReview<'info> {
             PlatformError::ReviewAlreadyApproved
         );
 
+        // Cancelled campaigns can still be moderated until the grace period ends
+        let current_time = Clock::get()?.unix_timestamp;
         require!(
-            self.campaign.active,
+            self.campaign.active
+                || (self.campaign.cancelled && current_time < self.campaign.grace_ends_at),
             PlatformError::CampaignNotActive
         );
 
+        if self.purchase_record.review == Pubkey::default() {
+            self.purchase_record.set_inner(PurchaseRecord {
+                campaign: self.campaign.key(),
+                review: self.review_account.key(),
+                reviewer: self.review_account.reviewer,
+                bump: bumps.purchase_record,
+            });
+        }
+
+        // A duplicate can still be flagged, it just can't be approved
+        require!(
+            !approved || self.purchase_record.review == self.review_account.key(),
+            PlatformError::DuplicatePurchaseProof
+        );
+
+        // Reviews migrated from before the counter existed were never counted
+        self.campaign.pending_reviews = self.campaign.pending_reviews.saturating_sub(1);
+
           let is_valid = self.validate_review_authenticity()?;
 
+        // K of N attestors must have vouched for the review before it can be approved
+        if approved {
+            require!(
+                self.count_passing_attestations(attestations)? >= self.platform.attestation_threshold,
+                PlatformError::AttestationRequired
+            );
+        }
+
         if approved && is_valid {
             self.review_account.approved = true;
             
@@ -79,6 +154,9 @@ impl<'info> ApproveReview<'info> {
             self.platform.total_reviews = self.platform.total_reviews
                 .checked_add(1)
                 .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            // Honest review, the whole bond goes back
+            self.settle_bond(false)?;
                 
         } else {
             // FLAG PATH
@@ -91,11 +169,71 @@ impl<'info> ApproveReview<'info> {
                 self.review_account.flagged_reason.len() <= 64,
                 PlatformError::ReasonTooLong
             );
+
+            self.settle_bond(true)?;
+        }
+
+        Ok(())
+    }
+
+    // Empties the bond vault: the bond goes to the reviewer, or to the slash target
+    // when flagged. The vault's rent always goes back to the reviewer.
+    pub fn settle_bond(&self, slash: bool) -> Result<()> {
+        let vault_balance = self.bond_vault.lamports();
+
+        if vault_balance == 0 {
+            return Ok(());
+        }
+
+        let slashed = match slash {
+            true => std::cmp::min(self.review_account.bond, vault_balance),
+            false => 0,
+        };
+
+        if slashed > 0 {
+            let target = match self.campaign.bond_slash_target {
+                BondSlashTarget::Merchant => self.merchant.to_account_info(),
+                BondSlashTarget::Treasury => self.treasury.to_account_info(),
+            };
+            self.transfer_from_bond_vault(target, slashed)?;
+        }
+
+        let returned = vault_balance
+            .checked_sub(slashed)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        if returned > 0 {
+            self.transfer_from_bond_vault(self.reviewer.to_account_info(), returned)?;
         }
 
         Ok(())
     }
 
+    pub fn transfer_from_bond_vault(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let review_key = self.review_account.key();
+        let seeds = &[
+            &b"bond"[..],
+            review_key.as_ref(),
+            &[self.review_account.bond_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.bond_vault.to_account_info(),
+            to,
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, amount)?;
+
+        Ok(())
+    }
+
    pub fn validate_review_authenticity(&self) -> Result<bool> {
        
         if self.review_account.tx_id.is_empty() {
@@ -115,17 +253,41 @@ impl<'info> ApproveReview<'info> {
           Ok(true)
     }
 
+    // Attestations aren't scoped to a platform and attestors can be removed, so the review's
+    // own tally isn't trusted. Only passing attestations from current attestors count, once each.
+    pub fn count_passing_attestations(&self, attestations: &[AccountInfo<'info>]) -> Result<u8> {
+        let review_key = self.review_account.key();
+        let mut counted: Vec<Pubkey> = Vec::with_capacity(attestations.len());
+
+        for info in attestations {
+            require!(
+                info.owner == &crate::ID,
+                PlatformError::InvalidAttestation
+            );
+
+            let attestation = Attestation::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+            require!(
+                attestation.review == review_key,
+                PlatformError::InvalidAttestation
+            );
+
+            if attestation.verdict
+                && self.platform.attestors.contains(&attestation.attestor)
+                && !counted.contains(&attestation.attestor)
+            {
+                counted.push(attestation.attestor);
+            }
+        }
+
+        u8::try_from(counted.len()).map_err(|_| error!(PlatformError::ArithmeticOverflow))
+    }
+
    pub fn update_reviewer_rank(&mut self) -> Result<()> {
 
         let approved_count = self.reviewer_account.approved_count;
         
-        let new_rank = match approved_count {
-            0..=4 => 1,      // Bronze
-            5..=14 => 2,     // Silver  
-            15..=49 => 3,    // Gold
-            50..=99 => 4,    // Platinum
-            _ => 5,          // Diamond
-        };
+        let new_rank = ReviewerAccount::rank_for(approved_count);
 
         self.reviewer_account.rank = new_rank;
         
@@ -0,0 +1,151 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_ID},
+    },
+};
+
+use crate::{errors::PlatformError, Attestation, PlatformConfig, ReviewAccount, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+#[instruction(attestor: Pubkey)]
+pub struct AttestReview<'info> {
+    // Anyone can relay the attestation, the attestor signs off-chain
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"attestation", review_account.key().as_ref(), attestor.as_ref()],
+        bump,
+        space = 8 + Attestation::INIT_SPACE
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub review_account: Account<'info, ReviewAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    /// CHECK: instructions sysvar, used to find the ed25519 verification
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttestReview<'info> {
+    pub fn attest_review(&mut self, attestor: Pubkey, verdict: bool, score: u8, bumps: &AttestReviewBumps) -> Result<()> {
+
+        require!(
+            self.platform.attestors.contains(&attestor),
+            PlatformError::UnauthorizedAttestor
+        );
+
+        require!(
+            score <= 100,
+            PlatformError::InvalidAttestationScore
+        );
+
+        // Attestations only matter before moderation
+        require!(
+            !self.review_account.approved && self.review_account.flagged_reason.is_empty(),
+            PlatformError::ReviewAlreadyApproved
+        );
+
+        // Signed message: review pubkey || verdict || score
+        let mut message = Vec::with_capacity(34);
+        message.extend_from_slice(self.review_account.key().as_ref());
+        message.push(verdict as u8);
+        message.push(score);
+
+        self.verify_ed25519_instruction(&attestor, &message)?;
+
+        self.attestation.set_inner(Attestation {
+            review: self.review_account.key(),
+            attestor,
+            verdict,
+            score,
+            timestamp: Clock::get()?.unix_timestamp,
+            bump: bumps.attestation,
+        });
+
+        if verdict {
+            self.review_account.passing_attestations = self.review_account.passing_attestations
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        } else {
+            self.review_account.failing_attestations = self.review_account.failing_attestations
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    // The instruction right before this one must be an ed25519 program
+    // verification of `message` signed by `attestor`
+    pub fn verify_ed25519_instruction(&self, attestor: &Pubkey, message: &[u8]) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+
+        let current_index = load_current_index_checked(&instructions)?;
+        require!(current_index > 0, PlatformError::InvalidAttestation);
+
+        let ix = load_instruction_at_checked((current_index - 1) as usize, &instructions)?;
+
+        require!(
+            ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+            PlatformError::InvalidAttestation
+        );
+
+        let data = &ix.data;
+
+        // [num_signatures, padding, 7 x u16 offsets, ...]
+        require!(
+            data.len() >= 16 && data[0] == 1,
+            PlatformError::InvalidAttestation
+        );
+
+        let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+
+        let signature_ix_index = read_u16(4);
+        let public_key_offset = read_u16(6) as usize;
+        let public_key_ix_index = read_u16(8);
+        let message_offset = read_u16(10) as usize;
+        let message_size = read_u16(12) as usize;
+        let message_ix_index = read_u16(14);
+
+        // Everything must live inside the ed25519 instruction itself
+        require!(
+            signature_ix_index == u16::MAX
+                && public_key_ix_index == u16::MAX
+                && message_ix_index == u16::MAX,
+            PlatformError::InvalidAttestation
+        );
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(PlatformError::InvalidAttestation)?;
+
+        let signed_message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(PlatformError::InvalidAttestation)?;
+
+        require!(
+            public_key == attestor.as_ref() && signed_message == message,
+            PlatformError::InvalidAttestation
+        );
+
+        Ok(())
+    }
+}
\ No newline at end of file
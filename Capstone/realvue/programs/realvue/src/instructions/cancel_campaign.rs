@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewCampaign, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct CancelCampaign<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant @ PlatformError::UnauthorizedMerchant,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelCampaign<'info> {
+    pub fn cancel_campaign(&mut self) -> Result<()> {
+
+        require!(
+            !self.campaign.cancelled,
+            PlatformError::CampaignCancelled
+        );
+
+        require!(
+            self.campaign.active,
+            PlatformError::CampaignNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Snapshot the platform policy so later changes can't move the goalposts
+        let grace_ends_at = current_time
+            .checked_add(self.platform.cancellation_grace_period)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let kill_fee = (self.campaign.reward_per_reviewer()? as u128)
+            .checked_mul(self.platform.kill_fee_bps as u128)
+            .ok_or(PlatformError::RewardCalculationError)?
+            .checked_div(10000)
+            .ok_or(PlatformError::RewardCalculationError)? as u64;
+
+        // Stops new reviews, moderation stays open until the grace period ends
+        self.campaign.active = false;
+        self.campaign.cancelled = true;
+        self.campaign.grace_ends_at = grace_ends_at;
+        self.campaign.kill_fee = kill_fee;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelWithdrawal<'info> {
+    pub fn cancel_withdrawal(&mut self) -> Result<()> {
+
+        require!(
+            self.platform.pending_withdrawal.is_some(),
+            PlatformError::NoPendingWithdrawal
+        );
+
+        self.platform.pending_withdrawal = None;
+
+        Ok(())
+    }
+}
\ No newline at end of file
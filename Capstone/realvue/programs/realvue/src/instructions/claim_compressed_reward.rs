@@ -0,0 +1,188 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{
+    compression::{CompressionAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    errors::PlatformError,
+    CompressedReview, CompressedReviewUpdated, PlatformConfig, ReviewCampaign, ReviewerAccount,
+    PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCompressedReward<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    /// CHECK: must be the campaign's tree, checked in the handler
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program, used by account-compression to log changes
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimCompressedReward<'info> {
+    // Pays the reward of an approved review, or the kill fee of a review still
+    // pending when a cancelled campaign's grace period ended
+    pub fn claim_compressed_reward(
+        &mut self,
+        review: CompressedReview,
+        root: [u8; 32],
+        proof: &[AccountInfo<'info>],
+    ) -> Result<()> {
+
+        require!(
+            self.campaign.merkle_tree == Some(self.merkle_tree.key()),
+            PlatformError::InvalidMerkleTree
+        );
+
+        require!(
+            review.campaign_id == self.campaign.key(),
+            PlatformError::InvalidCompressedReview
+        );
+
+        require!(
+            review.reviewer == self.reviewer.key(),
+            PlatformError::UnauthorizedReviewer
+        );
+
+        require!(
+            !review.reward_claimed,
+            PlatformError::RewardAlreadyClaimed
+        );
+
+        let amount = if review.approved {
+            self.campaign.rewards_claimed = self.campaign.rewards_claimed
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            self.campaign.reward_per_reviewer()?
+        } else {
+            require!(
+                review.is_pending() && self.campaign.cancelled,
+                PlatformError::ReviewNotApproved
+            );
+
+            let current_time = Clock::get()?.unix_timestamp;
+            require!(
+                current_time >= self.campaign.grace_ends_at,
+                PlatformError::GracePeriodActive
+            );
+
+            self.campaign.pending_reviews = self.campaign.pending_reviews.saturating_sub(1);
+
+            self.campaign.kill_fee
+        };
+
+        require!(
+            self.campaign.refundable_balance(self.vault.lamports()) >= amount,
+            PlatformError::InsufficientVaultFunds
+        );
+
+        let previous_leaf = review.leaf_hash()?;
+        let mut updated = review;
+        updated.reward_claimed = true;
+
+        let [first_seed, second_seed] = self.campaign.address_seeds();
+        let seeds = &[
+            &b"campaign"[..],
+            first_seed.as_ref(),
+            second_seed.as_ref(),
+            &[self.campaign.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let compression = CompressionAccounts {
+            compression_program: &self.compression_program.to_account_info(),
+            merkle_tree: &self.merkle_tree.to_account_info(),
+            authority: &self.campaign.to_account_info(),
+            noop_program: &self.noop_program.to_account_info(),
+        };
+
+        // Fails unless `review` is the current leaf, so a reward can't be claimed twice
+        compression.replace_leaf(
+            root,
+            previous_leaf,
+            updated.leaf_hash()?,
+            updated.leaf_index,
+            proof,
+            signer_seeds,
+        )?;
+
+        if amount > 0 {
+            self.transfer_from_vault(amount)?;
+
+            self.reviewer_account.total_earned = self.reviewer_account.total_earned
+                .checked_add(amount)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        }
+
+        emit!(CompressedReviewUpdated {
+            merkle_tree: self.merkle_tree.key(),
+            review: updated,
+        });
+
+        Ok(())
+    }
+
+    pub fn transfer_from_vault(&self, amount: u64) -> Result<()> {
+        let campaign_key = self.campaign.key();
+        let seeds = &[
+            &b"campaign_vault"[..],
+            campaign_key.as_ref(),
+            &[self.campaign.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.reviewer.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, amount)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use anchor_lang::{
     system_program::{transfer, Transfer},
 };
 
-use crate::{errors::PlatformError, PlatformConfig};
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
 
 #[derive(Accounts)]
 pub struct ClaimFee<'info> {
@@ -14,6 +14,7 @@ pub struct ClaimFee<'info> {
         mut,
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -24,10 +25,13 @@ pub struct ClaimFee<'info> {
     )]
     pub treasury: SystemAccount<'info>,
 
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 impl<'info> ClaimFee<'info> {
-    pub fn claim_fee(&mut self) -> Result<()> {
+    pub fn claim_fee(&mut self, amount: u64) -> Result<()> {
         
         // Manual checks
         require!(
@@ -35,10 +39,25 @@ impl<'info> ClaimFee<'info> {
             PlatformError::UnauthorizedAdmin
         );
 
-        let treasury_balance = self.treasury.lamports();
+        self.platform.apply_pending_timelock(Clock::get()?.unix_timestamp);
+
+        // With a timelock, withdrawals go through request_withdrawal/execute_withdrawal
+        require!(
+            self.platform.withdrawal_timelock == 0,
+            PlatformError::WithdrawalTimelocked
+        );
+
+        require!(
+            amount > 0,
+            PlatformError::InvalidWithdrawalAmount
+        );
+
+        // Treasury keeps its rent-exempt minimum
+        let rent_exempt: u64 = Rent::get()?.minimum_balance(self.treasury.to_account_info().data_len());
+        let withdrawable = self.treasury.lamports().saturating_sub(rent_exempt);
 
         require!(
-            treasury_balance > 0,
+            amount <= withdrawable,
             PlatformError::InsufficientTreasuryFunds
         );
 
@@ -52,7 +71,7 @@ impl<'info> ClaimFee<'info> {
 
         let cpi_accounts = Transfer {
             from: self.treasury.to_account_info(),
-            to: self.admin.to_account_info(),
+            to: self.destination.to_account_info(),
         };
 
         let ctx = CpiContext::new_with_signer(
@@ -61,15 +80,15 @@ impl<'info> ClaimFee<'info> {
             signer_seeds,
         );
 
-        transfer(ctx, treasury_balance)?;
+        transfer(ctx, amount)?;
 
-        // Update platform statistics with fees claimed
-        self.platform.total_fees_collected = self
+        // Fees were already counted as collected in create_campaign
+        self.platform.total_fees_withdrawn = self
             .platform
-            .total_fees_collected
-            .checked_add(treasury_balance)
+            .total_fees_withdrawn
+            .checked_add(amount)
             .ok_or(PlatformError::ArithmeticOverflow)?;
 
         Ok(())
     }
-}
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct ClaimKillFee<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign.key().as_ref(), reviewer.key().as_ref()],
+        bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub review_account: Account<'info, ReviewAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", review_account.key().as_ref()],
+        bump = review_account.bond_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimKillFee<'info> {
+    // Settles a review the moderators never got to before the cancelled campaign's grace period ended
+    pub fn claim_kill_fee(&mut self) -> Result<()> {
+
+        require!(
+            self.campaign.cancelled,
+            PlatformError::CampaignNotCancelled
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.campaign.grace_ends_at,
+            PlatformError::GracePeriodActive
+        );
+
+        require!(
+            !self.review_account.approved
+                && self.review_account.flagged_reason.is_empty()
+                && !self.review_account.reward_claimed,
+            PlatformError::ReviewNotPending
+        );
+
+        let kill_fee = self.campaign.kill_fee;
+
+        require!(
+            self.campaign.refundable_balance(self.vault.lamports()) >= kill_fee,
+            PlatformError::InsufficientVaultFunds
+        );
+
+        self.review_account.reward_claimed = true;
+
+        self.campaign.pending_reviews = self.campaign.pending_reviews.saturating_sub(1);
+
+        if kill_fee > 0 {
+            self.transfer_from_vault(kill_fee)?;
+
+            self.reviewer_account.total_earned = self.reviewer_account.total_earned
+                .checked_add(kill_fee)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        }
+
+        // The review was never judged, so the whole bond goes back
+        let bond_balance = self.bond_vault.lamports();
+        if bond_balance > 0 {
+            self.transfer_from_bond_vault(bond_balance)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn transfer_from_vault(&self, amount: u64) -> Result<()> {
+        let campaign_key = self.campaign.key();
+        let seeds = &[
+            &b"campaign_vault"[..],
+            campaign_key.as_ref(),
+            &[self.campaign.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.reviewer.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, amount)?;
+
+        Ok(())
+    }
+
+    pub fn transfer_from_bond_vault(&self, amount: u64) -> Result<()> {
+        let review_key = self.review_account.key();
+        let seeds = &[
+            &b"bond"[..],
+            review_key.as_ref(),
+            &[self.review_account.bond_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.bond_vault.to_account_info(),
+            to: self.reviewer.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, amount)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
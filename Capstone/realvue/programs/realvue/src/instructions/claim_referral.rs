@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReferrerAccount, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct ClaimReferral<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = referrer,
+        seeds = [b"referrer", platform.key().as_ref(), referrer.key().as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimReferral<'info> {
+    pub fn claim_referral(&mut self) -> Result<()> {
+
+        let amount = self.referrer_account.pending_rewards;
+
+        require!(
+            amount > 0,
+            PlatformError::NoReferralRewards
+        );
+
+        // Never dip into the rent the PDA needs to stay alive
+        let rent_exempt = Rent::get()?.minimum_balance(self.referrer_account.to_account_info().data_len());
+        let available = self.referrer_account.get_lamports()
+            .checked_sub(rent_exempt)
+            .ok_or(PlatformError::InsufficientVaultFunds)?;
+
+        require!(
+            available >= amount,
+            PlatformError::InsufficientVaultFunds
+        );
+
+        // The PDA is owned by this program, so lamports are moved directly
+        self.referrer_account.sub_lamports(amount)?;
+        self.referrer.add_lamports(amount)?;
+
+        self.referrer_account.pending_rewards = 0;
+
+        Ok(())
+    }
+}
\ No newline at end of file
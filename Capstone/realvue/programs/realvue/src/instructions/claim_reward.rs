@@ -1,6 +1,7 @@
 use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 
-use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount};
+use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount, RewardPayout, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
 
 #[derive(Accounts)]
 pub struct ClaimReward<'info> {
@@ -11,13 +12,16 @@ pub struct ClaimReward<'info> {
         mut,
         seeds = [campaign.key().as_ref(), review_account.reviewer.key().as_ref()],
         bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub review_account: Account<'info, ReviewAccount>,
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign.name.as_str().as_bytes(), campaign.merchant.key().as_ref()],
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
         bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
@@ -30,7 +34,7 @@ pub struct ClaimReward<'info> {
 
     #[account(
         mut,
-        seeds = [b"reviewer", reviewer.key().as_ref()],
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
         bump = reviewer_account.bump
     )]
     pub reviewer_account: Account<'info, ReviewerAccount>,
@@ -38,14 +42,38 @@ pub struct ClaimReward<'info> {
     #[account(
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
+    // Only needed while the reviewer owes rent, the sponsor that fronted it is repaid
+    #[account(
+        mut,
+        constraint = platform.review_sponsor == Some(sponsor.key()) @ PlatformError::UnauthorizedSponsor,
+    )]
+    pub sponsor: Option<SystemAccount<'info>>,
+
+    // Only needed for REV payouts
+    #[account(
+        mut,
+        address = platform.rev_mint
+    )]
+    pub rev_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = reviewer_rev_account.owner == reviewer.key() @ PlatformError::UnauthorizedReviewer,
+        constraint = reviewer_rev_account.mint == platform.rev_mint @ PlatformError::RevPayoutUnavailable,
+    )]
+    pub reviewer_rev_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimReward<'info> {
-    pub fn claim_reward(&mut self) -> Result<()> {
+    pub fn claim_reward(&mut self, payout: RewardPayout) -> Result<()> {
         // Manual checks
         require!(
             self.review_account.reviewer == self.reviewer.key(),
@@ -62,23 +90,88 @@ impl<'info> ClaimReward<'info> {
             PlatformError::RewardAlreadyClaimed
         );
 
+        // REV is paid out at once, so vesting campaigns only pay SOL
+        if payout == RewardPayout::Rev {
+            require!(
+                self.campaign.vesting.is_none() && self.platform.rev_payout_premium_bps.is_some(),
+                PlatformError::RevPayoutUnavailable
+            );
+        }
 
         // Calculate reward per reviewer
-        let reward_per_reviewer = self.calculate_reward_per_reviewer()?;
+        let reward = self.calculate_reward_per_reviewer()?;
 
         // Check if vault has sufficient balance
         let vault_balance = self.vault.lamports();
         require!(
-            vault_balance >= reward_per_reviewer,
+            self.campaign.refundable_balance(vault_balance) >= reward,
             PlatformError::InsufficientVaultFunds
         );
 
-        // Transfer reward to reviewer
-        self.transfer_reward_to_reviewer(reward_per_reviewer)?;
+        // Rent a sponsor fronted comes out of the reward first and goes back to the platform's sponsor,
+        // without one set the debt waits for the next claim
+        let rent_repaid = match &self.sponsor {
+            Some(sponsor) => {
+                let repaid = std::cmp::min(reward, self.reviewer_account.rent_debt);
+                if repaid > 0 {
+                    self.transfer_from_vault(sponsor.to_account_info(), repaid)?;
+                }
+                repaid
+            }
+            None => {
+                require!(
+                    self.reviewer_account.rent_debt == 0 || self.platform.review_sponsor.is_none(),
+                    PlatformError::SponsorRequired
+                );
+                0
+            }
+        };
+
+        self.reviewer_account.rent_debt -= rent_repaid;
+
+        let reward_per_reviewer = reward - rent_repaid;
 
         // Mark reward as claimed
         self.review_account.reward_claimed = true;
 
+        self.campaign.rewards_claimed = self.campaign.rewards_claimed
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        // Vesting campaigns keep the reward in the vault, withdraw_vested releases it
+        if self.campaign.vesting.is_some() {
+            self.review_account.vesting_total = reward_per_reviewer;
+            self.review_account.vesting_start = Clock::get()?.unix_timestamp;
+
+            self.campaign.vesting_locked = self.campaign.vesting_locked
+                .checked_add(reward_per_reviewer)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            return Ok(());
+        }
+
+        // The SOL stays in the vault and goes back to the merchant with the refund
+        if payout == RewardPayout::Rev {
+            let rev_amount = self.platform.rev_for_reward(reward_per_reviewer)?;
+            self.mint_rev_to_reviewer(rev_amount)?;
+
+            self.campaign.rev_paid_rewards = self.campaign.rev_paid_rewards
+                .checked_add(reward_per_reviewer)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            // Earnings count the SOL reward the REV was paid in place of
+            self.reviewer_account.total_earned = self.reviewer_account.total_earned
+                .checked_add(reward_per_reviewer)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            return Ok(());
+        }
+
+        // Transfer reward to reviewer
+        if reward_per_reviewer > 0 {
+            self.transfer_from_vault(self.reviewer.to_account_info(), reward_per_reviewer)?;
+        }
+
         // Update reviewer account stats
         self.reviewer_account.total_earned = self.reviewer_account.total_earned
             .checked_add(reward_per_reviewer)
@@ -87,28 +180,42 @@ impl<'info> ClaimReward<'info> {
         Ok(())
     }
 
-   pub fn calculate_reward_per_reviewer(&self) -> Result<u64> {
-        // Calculate platform fee
-        let fee_amount = (self.campaign.deposit_amount as u128)
-            .checked_mul(self.platform.platform_fee as u128)
-            .unwrap()
-            .checked_div(10000)
-            .unwrap() as u64;
-
-        // Amount available for rewards (deposit - platform fee)
-        let reward_pool = self.campaign.deposit_amount
-            .checked_sub(fee_amount)
-            .ok_or(PlatformError::ArithmeticOverflow)?;
+    pub fn mint_rev_to_reviewer(&self, amount: u64) -> Result<()> {
+        let (Some(rev_mint), Some(reviewer_rev_account), Some(token_program)) =
+            (&self.rev_mint, &self.reviewer_rev_account, &self.token_program)
+        else {
+            return err!(PlatformError::RevPayoutUnavailable);
+        };
 
-        // Reward per reviewer (reward pool / reviews needed)
-        let reward_per_reviewer = reward_pool
-            .checked_div(self.campaign.reviews_needed as u64)
-            .ok_or(PlatformError::RewardCalculationError)?;
+        let admin_key = self.platform.admin.key();
+        let seed = self.platform.seed.to_le_bytes();
+        let seeds = &[
+            &b"realvue"[..],
+            seed.as_ref(),
+            admin_key.as_ref(),
+            &[self.platform.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: rev_mint.to_account_info(),
+            to: reviewer_rev_account.to_account_info(),
+            authority: self.platform.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
 
-        Ok(reward_per_reviewer)
+        mint_to(ctx, amount)?;
+
+        Ok(())
+    }
+
+   pub fn calculate_reward_per_reviewer(&self) -> Result<u64> {
+        // Platform fee snapshotted when the campaign was created
+        self.campaign.reward_per_reviewer()
     }
 
-    pub fn transfer_reward_to_reviewer(&self, amount: u64) -> Result<()> {
+    pub fn transfer_from_vault(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
         let campaign_key = self.campaign.key();
         let seeds = &[
             &b"campaign_vault"[..],
@@ -119,7 +226,7 @@ impl<'info> ClaimReward<'info> {
 
         let cpi_accounts = Transfer {
             from: self.vault.to_account_info(),
-            to: self.reviewer.to_account_info(),
+            to,
         };
 
         let ctx = CpiContext::new_with_signer(
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct ClawbackReward<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign.key().as_ref(), review_account.reviewer.key().as_ref()],
+        bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub review_account: Account<'info, ReviewAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        has_one = admin, // Only the platform admin moderates reviews
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), review_account.reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClawbackReward<'info> {
+    pub fn clawback_reward(&mut self, reason: String) -> Result<()> {
+
+        let schedule = self
+            .campaign
+            .vesting
+            .ok_or(PlatformError::VestingNotEnabled)?;
+
+        require!(
+            self.review_account.approved && self.review_account.reward_claimed,
+            PlatformError::ReviewNotApproved
+        );
+
+        require!(
+            reason.len() <= 64,
+            PlatformError::ReasonTooLong
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let window_end = self.review_account.vesting_start
+            .checked_add(schedule.clawback_window)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        require!(
+            current_time <= window_end,
+            PlatformError::ClawbackWindowClosed
+        );
+
+        // Reviewer keeps what already vested, the rest goes back to the vault
+        let vested = schedule.vested_amount(
+            self.review_account.vesting_total,
+            self.review_account.vesting_start,
+            current_time,
+        )?;
+
+        let unvested = self.review_account.vesting_total
+            .checked_sub(vested)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.review_account.vesting_total = vested;
+
+        self.campaign.vesting_locked = self.campaign.vesting_locked
+            .checked_sub(unvested)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        // Overturn the review, its claim no longer counts towards the campaign
+        self.review_account.approved = false;
+        self.review_account.flagged_reason = reason;
+
+        self.campaign.approved_count = self.campaign.approved_count
+            .checked_sub(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.campaign.rewards_claimed = self.campaign.rewards_claimed
+            .checked_sub(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        // Reviewer loses the approval and the rank it earned
+        self.reviewer_account.approved_count = self.reviewer_account.approved_count
+            .checked_sub(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.reviewer_account.rank = ReviewerAccount::rank_for(self.reviewer_account.approved_count);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::PlatformError, PlatformConfig, ReviewCampaign};
+use crate::{errors::PlatformError, PlatformConfig, ReviewCampaign, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION};
 
 #[derive(Accounts)]
 pub struct CloseCampaign<'info> {
@@ -9,9 +9,11 @@ pub struct CloseCampaign<'info> {
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign.name.as_str().as_bytes(), campaign.merchant.key().as_ref()],
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
         bump = campaign.bump,
-        close = merchant
+        close = merchant,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
@@ -19,6 +21,7 @@ pub struct CloseCampaign<'info> {
         mut,
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -40,6 +43,24 @@ impl<'info> CloseCampaign<'info> {
             PlatformError::CampaignNotActive
         );
 
+        // Pending reviewers would get nothing, cancel_campaign settles them first
+        require!(
+            self.campaign.pending_reviews == 0,
+            PlatformError::ReviewsPending
+        );
+
+        // Approved reviewers still claim from the campaign
+        require!(
+            self.campaign.rewards_claimed >= self.campaign.approved_count,
+            PlatformError::RewardsUnclaimed
+        );
+
+        // Closing would strand rewards that are still vesting
+        require!(
+            self.campaign.vesting_locked == 0,
+            PlatformError::VestingInProgress
+        );
+
         // Check if campaign has reached its end time or target
         // let current_time = Clock::get()?.unix_timestamp;
         // let can_close = current_time >= self.campaign.end_time || 
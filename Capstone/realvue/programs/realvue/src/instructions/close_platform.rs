@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::PlatformError,  state::PlatformConfig};
+use crate::{errors::PlatformError,  state::{PlatformConfig, PLATFORM_CONFIG_VERSION}};
 
 #[derive(Accounts)]
 pub struct ClosePlatform<'info> {
@@ -12,6 +12,7 @@ pub struct ClosePlatform<'info> {
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
         close = admin,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
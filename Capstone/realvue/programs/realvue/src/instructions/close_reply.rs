@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{ReviewReply, ReviewReplyUpdated};
+
+#[derive(Accounts)]
+pub struct CloseReply<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        close = merchant,
+        has_one = merchant,
+        seeds = [b"reply", reply.review.as_ref()],
+        bump = reply.bump
+    )]
+    pub reply: Account<'info, ReviewReply>,
+}
+
+impl<'info> CloseReply<'info> {
+    pub fn close_reply(&mut self) -> Result<()> {
+
+        emit!(ReviewReplyUpdated::from_reply(self.reply.key(), &self.reply, true));
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::ReviewerAccount;
+use crate::{errors::PlatformError, ReviewerAccount};
 
 #[derive(Accounts)]
 pub struct CloseReviewer<'info> {
@@ -9,7 +9,7 @@ pub struct CloseReviewer<'info> {
     #[account(
         mut,
         close = reviewer, 
-        seeds = [b"reviewer", reviewer.key().as_ref()],
+        seeds = [b"reviewer", reviewer_account.platform.key().as_ref(), reviewer.key().as_ref()],
         bump = reviewer_account.bump
     )]
     pub reviewer_account: Account<'info, ReviewerAccount>,
@@ -18,6 +18,12 @@ pub struct CloseReviewer<'info> {
 
 impl<'info> CloseReviewer<'info> {
     pub fn close_reviewer(&mut self) -> Result<()> {
+        // Closing and re-initializing would otherwise wipe what the sponsor is owed
+        require!(
+            self.reviewer_account.rent_debt == 0,
+            PlatformError::RentDebtOutstanding
+        );
+
         Ok(())
     }
 }
\ No newline at end of file
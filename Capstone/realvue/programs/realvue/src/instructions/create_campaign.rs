@@ -1,23 +1,41 @@
 use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
 use anchor_spl::{associated_token::{AssociatedToken}, token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface}};
 
-use crate::{errors::PlatformError,  state::{PlatformConfig, ReviewCampaign}};
+use crate::{errors::PlatformError,  state::{Category, MerchantAccount, MerchantCounter, PlatformConfig, ReferrerAccount, ReviewCampaign, VestingSchedule, BondSlashTarget, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION}};
 
 #[derive(Accounts)]
-#[instruction(name: String)]
 pub struct CreateCampaign<'info> {
     #[account(mut)]
     pub merchant: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = merchant,
+        seeds = [b"merchant_counter", merchant.key().as_ref()],
+        bump,
+        space = 8 + MerchantCounter::INIT_SPACE
+    )]
+    pub merchant_counter: Account<'info, MerchantCounter>,
+
+    // Keyed by id so names can be reused and changed, (merchant, id) finds the campaign
     #[account(
         init, 
         payer = merchant,
-        seeds = [b"campaign", name.as_str().as_bytes(), merchant.key().as_ref()],
+        seeds = [b"campaign", merchant.key().as_ref(), merchant_counter.next_campaign_id().to_le_bytes().as_ref()],
         bump,
         space = 8 + ReviewCampaign::INIT_SPACE
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
+    #[account(
+        init_if_needed,
+        payer = merchant,
+        seeds = [b"merchant", platform.key().as_ref(), merchant.key().as_ref()],
+        bump,
+        space = 8 + MerchantAccount::INIT_SPACE
+    )]
+    pub merchant_account: Account<'info, MerchantAccount>,
+
     #[account(
         init_if_needed,
         payer = merchant,
@@ -31,6 +49,7 @@ pub struct CreateCampaign<'info> {
         has_one = rev_mint,
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -59,36 +78,104 @@ pub struct CreateCampaign<'info> {
     )]
     pub treasury: SystemAccount<'info>,
 
+    #[account(
+        has_one = platform,
+        seeds = [b"category", platform.key().as_ref(), category.name.as_bytes()],
+        bump = category.bump,
+        constraint = category.active @ PlatformError::CategoryInactive,
+    )]
+    pub category: Account<'info, Category>,
+
+    // Optional agency that brought the merchant in, receives a share of the fee
+    #[account(
+        mut,
+        seeds = [b"referrer", platform.key().as_ref(), referrer_account.referrer.key().as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Option<Account<'info, ReferrerAccount>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateCampaign<'info>{
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(&mut self, name: String, product_id: String,
-    deposit_amount: u64, start_time: i64, end_time: i64, reviews_needed: u16, bumps: &CreateCampaignBumps) -> Result<()>{
+    deposit_amount: u64, start_time: i64, end_time: i64, reviews_needed: u16, vesting: Option<VestingSchedule>, bumps: &CreateCampaignBumps) -> Result<()>{
+
+        ReviewCampaign::validate_name(&name)?;
 
-        const MIN_DEPOSIT: u64 = 100_000_000;
         require!(
-            // Minimum deposit check ( 0.1 SOL = 100,000,000 lamports)
-            deposit_amount >= MIN_DEPOSIT,
+            deposit_amount >= self.category.policy.min_deposit,
             PlatformError::InsufficientDepositAmount
         );
 
+        if let Some(schedule) = &vesting {
+            require!(
+                schedule.cliff >= 0 && schedule.duration >= 0 && schedule.clawback_window >= 0,
+                PlatformError::InvalidVestingSchedule
+            );
+        }
+
+        if self.merchant_counter.merchant == Pubkey::default() {
+            self.merchant_counter.set_inner(MerchantCounter {
+                merchant: self.merchant.key(),
+                campaign_count: 0,
+                bump: bumps.merchant_counter,
+            });
+        }
+
+        let campaign_id = self.merchant_counter.campaign_count
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+        self.merchant_counter.campaign_count = campaign_id;
+
+        if self.merchant_account.merchant == Pubkey::default() {
+            self.merchant_account.set_inner(MerchantAccount {
+                merchant: self.merchant.key(),
+                platform: self.platform.key(),
+                total_deposited: 0,
+                total_campaigns: 0,
+                bump: bumps.merchant_account,
+            });
+        }
+
+        // Category override wins, otherwise the tier is picked from the merchant's volume before this deposit
+        let fee_bps = self.category.policy.fee_bps
+            .unwrap_or_else(|| self.platform.fee_for_volume(self.merchant_account.total_deposited));
 
         self.campaign.set_inner(ReviewCampaign {
+            version: REVIEW_CAMPAIGN_VERSION,
             merchant: self.merchant.key(),
             name,
             product_id, 
             deposit_amount, 
+            fee_bps,
             start_time, 
             end_time, 
             active: true, 
             reviews_needed, 
             approved_count: 0,
             refunded: false, 
+            vesting,
+            vesting_locked: 0,
+            bond_amount: 0,
+            bond_slash_target: BondSlashTarget::Merchant,
             vault_bump: bumps.vault,
-            bump: bumps.campaign
+            bump: bumps.campaign,
+            pending_reviews: 0,
+            rewards_claimed: 0,
+            cancelled: false,
+            grace_ends_at: 0,
+            kill_fee: 0,
+            merkle_tree: None,
+            compressed_reviews: 0,
+            category: self.category.key(),
+            rent_budget: 0,
+            campaign_id,
+            rev_paid_rewards: 0,
+            platform: self.platform.key(),
             });
 
         let rent_exempt: u64 = Rent::get()?.minimum_balance(self.vault.to_account_info().data_len());
@@ -116,19 +203,15 @@ impl<'info> CreateCampaign<'info>{
 
             transfer(ctx, deposit_amount)?;
 
-            // Calculate fee amount based on fee percentage (basis points)
-            let fee_amount = (deposit_amount as u128)
-                .checked_mul(self.platform.platform_fee as u128)
-                .unwrap()
-                .checked_div(10000)
-                .unwrap() as u64;
+            // Calculate fee amount based on the snapshotted fee (basis points)
+            let fee_amount = self.campaign.fee_amount()?;
 
             // Mint REV tokens equal to deposited SOL (1:1 ratio)
             let rev_amount = deposit_amount;
 
             self.mint_rev_token(rev_amount)?;
 
-            self.deduct_fee(fee_amount)?;
+            let treasury_amount = self.deduct_fee(fee_amount)?;
 
             // Update platform statistics
         self.platform.total_campaigns = self.platform.total_campaigns
@@ -136,7 +219,16 @@ impl<'info> CreateCampaign<'info>{
             .ok_or(PlatformError::ArithmeticOverflow)?;
 
         self.platform.total_fees_collected = self.platform.total_fees_collected
-            .checked_add(fee_amount)
+            .checked_add(treasury_amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        // Update merchant volume
+        self.merchant_account.total_deposited = self.merchant_account.total_deposited
+            .checked_add(deposit_amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.merchant_account.total_campaigns = self.merchant_account.total_campaigns
+            .checked_add(1)
             .ok_or(PlatformError::ArithmeticOverflow)?;
             
         Ok(())
@@ -168,7 +260,9 @@ impl<'info> CreateCampaign<'info>{
 
         Ok(())
     }
-    pub fn deduct_fee(&self, amount: u64) -> Result<()>{
+    // Sends the fee out of the vault, splitting off the referrer share if one is set.
+    // Returns the amount that went to the treasury.
+    pub fn deduct_fee(&mut self, amount: u64) -> Result<u64>{
 
         let vault_balance = self.vault.lamports();
     
@@ -176,6 +270,52 @@ impl<'info> CreateCampaign<'info>{
             vault_balance >= amount,
             PlatformError::InsufficientVaultFunds
         );
+
+        let referral_amount = match &self.referrer_account {
+            Some(referrer_account) => {
+                require!(
+                    referrer_account.referrer != self.merchant.key(),
+                    PlatformError::SelfReferral
+                );
+
+                (amount as u128)
+                    .checked_mul(self.platform.referral_share_bps as u128)
+                    .ok_or(PlatformError::FeeCalculationError)?
+                    .checked_div(10000)
+                    .ok_or(PlatformError::FeeCalculationError)? as u64
+            }
+            None => 0,
+        };
+
+        let treasury_amount = amount
+            .checked_sub(referral_amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.transfer_from_vault(self.treasury.to_account_info(), treasury_amount)?;
+
+        if let Some(referrer_account) = &self.referrer_account {
+            self.transfer_from_vault(referrer_account.to_account_info(), referral_amount)?;
+        }
+
+        // Referrer claims its share later from its own PDA
+        if let Some(referrer_account) = &mut self.referrer_account {
+            referrer_account.pending_rewards = referrer_account.pending_rewards
+                .checked_add(referral_amount)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            referrer_account.total_earned = referrer_account.total_earned
+                .checked_add(referral_amount)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            referrer_account.total_campaigns = referrer_account.total_campaigns
+                .checked_add(1)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+        }
+
+        Ok(treasury_amount)
+    }
+
+    pub fn transfer_from_vault(&self, to: AccountInfo<'info>, amount: u64) -> Result<()>{
         let cpi_program = self.system_program.to_account_info();
 
         let campaign_key = self.campaign.key();
@@ -189,7 +329,7 @@ impl<'info> CreateCampaign<'info>{
 
         let cpi_accounts= Transfer{
             from: self.vault.to_account_info(),
-            to: self.treasury.to_account_info(),
+            to,
         };
 
         let ctx= CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, Category, CategoryPolicy, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateCategory<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"category", platform.key().as_ref(), name.as_bytes()],
+        bump,
+        space = 8 + Category::INIT_SPACE
+    )]
+    pub category: Account<'info, Category>,
+
+    #[account(
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateCategory<'info> {
+    pub fn create_category(&mut self, name: String, policy: CategoryPolicy, bumps: &CreateCategoryBumps) -> Result<()> {
+
+        require!(
+            !name.is_empty() && name.len() <= 32,
+            PlatformError::InvalidCategoryName
+        );
+
+        policy.validate()?;
+
+        self.category.set_inner(Category {
+            platform: self.platform.key(),
+            name,
+            policy,
+            active: true,
+            bump: bumps.category,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ReviewReply, ReviewReplyUpdated};
+
+#[derive(Accounts)]
+pub struct EditReply<'info> {
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant,
+        seeds = [b"reply", reply.review.as_ref()],
+        bump = reply.bump
+    )]
+    pub reply: Account<'info, ReviewReply>,
+}
+
+impl<'info> EditReply<'info> {
+    pub fn edit_reply(&mut self, body: String) -> Result<()> {
+
+        ReviewReply::validate_body(&body)?;
+
+        // Hidden replies stay hidden, the admin decides whether the edit fixed it
+        self.reply.body = body;
+        self.reply.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(ReviewReplyUpdated::from_reply(self.reply.key(), &self.reply, false));
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    compression::{CompressionAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    errors::PlatformError,
+    PlatformConfig, ReviewCampaign, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct EnableCompression<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant @ PlatformError::UnauthorizedMerchant,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    /// CHECK: allocated by the client for the chosen depth and buffer size, initialized by the compression program
+    #[account(
+        mut,
+        owner = SPL_ACCOUNT_COMPRESSION_ID
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program, used by account-compression to log changes
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EnableCompression<'info> {
+    pub fn enable_compression(&mut self, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+
+        require!(
+            self.campaign.merkle_tree.is_none(),
+            PlatformError::CampaignCompressed
+        );
+
+        require!(
+            self.campaign.active,
+            PlatformError::CampaignNotActive
+        );
+
+        // Switching modes is only safe before any review exists
+        require!(
+            self.campaign.pending_reviews == 0 && self.campaign.approved_count == 0,
+            PlatformError::ReviewsPending
+        );
+
+        require!(
+            self.campaign.bond_amount == 0 && self.campaign.vesting.is_none(),
+            PlatformError::CompressionUnsupported
+        );
+
+        let [first_seed, second_seed] = self.campaign.address_seeds();
+        let seeds = &[
+            &b"campaign"[..],
+            first_seed.as_ref(),
+            second_seed.as_ref(),
+            &[self.campaign.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // The campaign PDA becomes the tree authority, only this program can append or replace leaves
+        let compression = CompressionAccounts {
+            compression_program: &self.compression_program.to_account_info(),
+            merkle_tree: &self.merkle_tree.to_account_info(),
+            authority: &self.campaign.to_account_info(),
+            noop_program: &self.noop_program.to_account_info(),
+        };
+
+        compression.init_empty_merkle_tree(max_depth, max_buffer_size, signer_seeds)?;
+
+        self.campaign.merkle_tree = Some(self.merkle_tree.key());
+
+        Ok(())
+    }
+}
\ No newline at end of file
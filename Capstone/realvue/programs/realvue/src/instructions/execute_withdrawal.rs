@@ -0,0 +1,95 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", platform.key().as_ref()],
+        bump = platform.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteWithdrawal<'info> {
+    pub fn execute_withdrawal(&mut self) -> Result<()> {
+
+        let pending = self
+            .platform
+            .pending_withdrawal
+            .ok_or(PlatformError::NoPendingWithdrawal)?;
+
+        require!(
+            self.destination.key() == pending.destination,
+            PlatformError::InvalidWithdrawalDestination
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            current_time >= pending.executable_at,
+            PlatformError::WithdrawalNotReady
+        );
+
+        // Treasury keeps its rent-exempt minimum
+        let rent_exempt: u64 = Rent::get()?.minimum_balance(self.treasury.to_account_info().data_len());
+        let withdrawable = self.treasury.lamports().saturating_sub(rent_exempt);
+
+        require!(
+            pending.amount <= withdrawable,
+            PlatformError::InsufficientTreasuryFunds
+        );
+
+        let platform_key = self.platform.key();
+        let seeds = &[
+            &b"treasury"[..],
+            platform_key.as_ref(),
+            &[self.platform.treasury_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.treasury.to_account_info(),
+            to: self.destination.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, pending.amount)?;
+
+        self.platform.total_fees_withdrawn = self
+            .platform
+            .total_fees_withdrawn
+            .checked_add(pending.amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.platform.pending_withdrawal = None;
+
+        Ok(())
+    }
+}
\ No newline at end of file
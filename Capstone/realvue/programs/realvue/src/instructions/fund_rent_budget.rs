@@ -0,0 +1,58 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{errors::PlatformError, ReviewCampaign, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct FundRentBudget<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundRentBudget<'info> {
+    // Lets sponsored reviewers keep their whole reward, what's left goes back with the refund
+    pub fn fund_rent_budget(&mut self, amount: u64) -> Result<()> {
+
+        require!(
+            amount > 0,
+            PlatformError::InvalidRentBudget
+        );
+
+        require!(
+            self.campaign.active,
+            PlatformError::CampaignNotActive
+        );
+
+        let cpi_accounts = Transfer {
+            from: self.merchant.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(ctx, amount)?;
+
+        self.campaign.rent_budget = self.campaign.rent_budget
+            .checked_add(amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewerAccount, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct ImportReputation<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = reviewer,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    // The same reviewer's account on the platform being imported from
+    #[account(
+        has_one = reviewer,
+        seeds = [b"reviewer", source_reviewer_account.platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = source_reviewer_account.bump
+    )]
+    pub source_reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ImportReputation<'info> {
+    pub fn import_reputation(&mut self) -> Result<()> {
+
+        require!(
+            self.platform.is_active,
+            PlatformError::PlatformInactive
+        );
+
+        require!(
+            self.platform.trusted_platforms.contains(&self.source_reviewer_account.platform),
+            PlatformError::UntrustedPlatform
+        );
+
+        // Take the better record instead of adding, so repeated imports can't inflate it
+        let approved_count = std::cmp::max(
+            self.reviewer_account.approved_count,
+            self.source_reviewer_account.approved_count,
+        );
+
+        self.reviewer_account.approved_count = approved_count;
+        self.reviewer_account.rank = ReviewerAccount::rank_for(approved_count);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use anchor_spl::{
     token_interface::{Mint, TokenInterface},
 };
 
-use crate::{errors::PlatformError, state::PlatformConfig};
+use crate::{errors::PlatformError, state::{PlatformConfig, PLATFORM_CONFIG_VERSION}};
 
 #[derive(Accounts)]
 #[instruction(seed: u64)]
@@ -54,17 +54,31 @@ impl<'info> InitializePlatform<'info> {
         require!(platform_fee <= 1000, PlatformError::InvalidFeePercentage);
 
         self.platform.set_inner(PlatformConfig {
+            version: PLATFORM_CONFIG_VERSION,
             seed,
             admin: self.admin.key(),
             rev_mint: self.rev_mint.key(),
             platform_fee,
+            fee_tiers: Vec::new(),
+            referral_share_bps: 0,
             is_active: true,
             total_campaigns: 0,
             total_reviews: 0,
             total_fees_collected: 0,
+            total_fees_withdrawn: 0,
+            withdrawal_timelock: 0,
+            pending_withdrawal: None,
+            trusted_platforms: Vec::new(),
             rev_bump: bumps.rev_mint,
             treasury_bump: bumps.treasury,
             bump: bumps.platform,
+            attestors: Vec::new(),
+            attestation_threshold: 0,
+            cancellation_grace_period: 0,
+            kill_fee_bps: 0,
+            review_sponsor: None,
+            rev_payout_premium_bps: None,
+            pending_timelock: None,
         });
 
         // Rent exempt, the minimum balance that an account needs to become active or initialized
@@ -1,35 +1,61 @@
 use anchor_lang::prelude::*;
 
-use crate::ReviewerAccount;
+use crate::{errors::PlatformError, PlatformConfig, ReviewerAccount, PLATFORM_CONFIG_VERSION};
 
 #[derive(Accounts)]
 pub struct InitializeReviewer<'info> {
+    // The reviewer, or the platform's review sponsor fronting the rent
     #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub reviewer: Signer<'info>,
 
     #[account(
         init_if_needed,
-        payer = reviewer,
-        seeds = [b"reviewer", reviewer.key().as_ref()],
+        payer = payer,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
         space = 8 + ReviewerAccount::INIT_SPACE,
         bump
     )]
     pub reviewer_account: Account<'info, ReviewerAccount>,
 
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> InitializeReviewer<'info> {
     pub fn init_reviewer(&mut self, bumps: &InitializeReviewerBumps) -> Result<()> {
 
+        let sponsored = self.platform.is_sponsored(self.payer.key(), self.reviewer.key())?;
+
+        // Already initialized, re-running must not reset stats or wipe rent debt
+        if self.reviewer_account.reviewer != Pubkey::default() {
+            return Ok(());
+        }
+
+        // Sponsored rent is repaid out of the reviewer's first rewards
+        let rent_debt = match sponsored {
+            true => self.reviewer_account.to_account_info().lamports(),
+            false => 0,
+        };
+
         self.reviewer_account.set_inner(ReviewerAccount {
             reviewer: self.reviewer.key(),
+            platform: self.platform.key(),
             approved_count: 0,
             rank: 1,
             total_earned: 0,
+            badge_rank: 0,
+            rent_debt,
             bump: bumps.reviewer_account,
         });
 
         Ok(())
     }
-}
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    compression::{CompressionAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    errors::PlatformError,
+    Category, CompressedReview, CompressedReviewMarker, CompressedReviewUpdated, PlatformConfig, PurchaseRecord, ReviewCampaign, ReviewerAccount,
+    PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION,
+};
+
+#[derive(Accounts)]
+#[instruction(description: String, tx_id: String)]
+pub struct MakeCompressedReview<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub category: Option<Account<'info, Category>>,
+
+    // Same one-review-per-reviewer limit the ReviewAccount PDA gives uncompressed campaigns
+    #[account(
+        init,
+        payer = reviewer,
+        seeds = [b"compressed_review", campaign.key().as_ref(), reviewer.key().as_ref()],
+        bump,
+        space = 8 + CompressedReviewMarker::INIT_SPACE
+    )]
+    pub review_marker: Account<'info, CompressedReviewMarker>,
+
+    // init_if_needed so a reused tx_id gets DuplicatePurchaseProof instead of a bare "already in use"
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        seeds = [b"purchase", PurchaseRecord::purchase_hash(&campaign.key(), &tx_id).as_ref()],
+        bump,
+        space = 8 + PurchaseRecord::INIT_SPACE
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// CHECK: must be the campaign's tree, checked in the handler
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program, used by account-compression to log changes
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MakeCompressedReview<'info> {
+    pub fn make_compressed_review(
+        &mut self,
+        description: String,
+        tx_id: String,
+        bumps: &MakeCompressedReviewBumps,
+    ) -> Result<()> {
+
+        require!(
+            self.campaign.merkle_tree == Some(self.merkle_tree.key()),
+            PlatformError::InvalidMerkleTree
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            self.campaign.active,
+            PlatformError::CampaignNotActive
+        );
+
+        require!(
+            current_time >= self.campaign.start_time,
+            PlatformError::CampaignNotStarted
+        );
+
+        require!(
+            current_time <= self.campaign.end_time,
+            PlatformError::CampaignEnded
+        );
+
+        require!(
+            !description.is_empty(),
+            PlatformError::EmptyReviewDescription
+        );
+
+        let (min_reviewer_rank, max_description_len) =
+            Category::review_limits(self.campaign.category, self.category.as_ref())?;
+
+        require!(
+            description.len() <= max_description_len as usize,
+            PlatformError::ReviewDescriptionTooLong
+        );
+
+        require!(
+            self.reviewer_account.rank >= min_reviewer_rank,
+            PlatformError::ReviewerRankTooLow
+        );
+
+        require!(
+            !tx_id.is_empty() && tx_id.len() <= 88,
+            PlatformError::InvalidTransactionId
+        );
+
+        // One purchase proof backs at most one review per campaign
+        require!(
+            self.purchase_record.review == Pubkey::default(),
+            PlatformError::DuplicatePurchaseProof
+        );
+
+        // Leaves have no account of their own, the record points at the tree holding the review
+        self.purchase_record.set_inner(PurchaseRecord {
+            campaign: self.campaign.key(),
+            review: self.merkle_tree.key(),
+            reviewer: self.reviewer.key(),
+            bump: bumps.purchase_record,
+        });
+
+        self.review_marker.set_inner(CompressedReviewMarker {
+            campaign: self.campaign.key(),
+            reviewer: self.reviewer.key(),
+            leaf_index: self.campaign.compressed_reviews,
+            bump: bumps.review_marker,
+        });
+
+        let review = CompressedReview {
+            campaign_id: self.campaign.key(),
+            reviewer: self.reviewer.key(),
+            description,
+            approved: false,
+            reward_claimed: false,
+            flagged_reason: "".to_string(),
+            tx_id,
+            reviewer_rank: self.reviewer_account.rank,
+            timestamp: current_time,
+            leaf_index: self.campaign.compressed_reviews,
+        };
+
+        let [first_seed, second_seed] = self.campaign.address_seeds();
+        let seeds = &[
+            &b"campaign"[..],
+            first_seed.as_ref(),
+            second_seed.as_ref(),
+            &[self.campaign.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let compression = CompressionAccounts {
+            compression_program: &self.compression_program.to_account_info(),
+            merkle_tree: &self.merkle_tree.to_account_info(),
+            authority: &self.campaign.to_account_info(),
+            noop_program: &self.noop_program.to_account_info(),
+        };
+
+        compression.append(review.leaf_hash()?, signer_seeds)?;
+
+        self.campaign.compressed_reviews = self.campaign.compressed_reviews
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.campaign.pending_reviews = self.campaign.pending_reviews
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.platform.total_reviews = self.platform.total_reviews
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        emit!(CompressedReviewUpdated {
+            merkle_tree: self.merkle_tree.key(),
+            review,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,15 +1,21 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
 
-use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount};
+use crate::{errors::PlatformError, Category, PlatformConfig, PurchaseRecord, ReviewAccount, ReviewCampaign, ReviewerAccount, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
 
 #[derive(Accounts)]
+#[instruction(description: String, tx_id: String)]
 pub struct MakeReview<'info> {
+    // The reviewer, or the platform's review sponsor fronting the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Still pays the bond, a sponsor only covers rent
     #[account(mut)]
     pub reviewer: Signer<'info>,
 
     #[account(
         init,
-        payer = reviewer,
+        payer = payer,
         seeds = [campaign.key().as_ref(), reviewer.key().as_ref()],
         bump,
         space = 8 + ReviewAccount::INIT_SPACE
@@ -18,14 +24,23 @@ pub struct MakeReview<'info> {
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign.name.as_str().as_bytes(), campaign.merchant.key().as_ref()],
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
         bump= campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
     #[account(
         mut,
-        seeds = [b"reviewer", reviewer_account.reviewer.key().as_ref()],
+        seeds = [b"campaign_vault", campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
         bump = reviewer_account.bump
     )]
     pub reviewer_account: Account<'info, ReviewerAccount>,
@@ -34,9 +49,29 @@ pub struct MakeReview<'info> {
         mut,
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
+    pub category: Option<Account<'info, Category>>,
+
+    // init_if_needed so a reused tx_id gets DuplicatePurchaseProof instead of a bare "already in use"
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"purchase", PurchaseRecord::purchase_hash(&campaign.key(), &tx_id).as_ref()],
+        bump,
+        space = 8 + PurchaseRecord::INIT_SPACE
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", review_account.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -70,17 +105,48 @@ impl<'info> MakeReview<'info> {
             PlatformError::EmptyReviewDescription
         );
 
+        let (min_reviewer_rank, max_description_len) =
+            Category::review_limits(self.campaign.category, self.category.as_ref())?;
+
         require!(
-            description.len() <= 500,
+            description.len() <= max_description_len as usize,
             PlatformError::ReviewDescriptionTooLong
         );
 
+        require!(
+            self.reviewer_account.rank >= min_reviewer_rank,
+            PlatformError::ReviewerRankTooLow
+        );
+
         require!(
             !tx_id.is_empty(),
             PlatformError::InvalidTransactionId
         );
 
+        // Compressed campaigns take reviews through make_compressed_review
+        require!(
+            self.campaign.merkle_tree.is_none(),
+            PlatformError::CampaignCompressed
+        );
+
+        // One purchase proof backs at most one review per campaign
+        require!(
+            self.purchase_record.review == Pubkey::default(),
+            PlatformError::DuplicatePurchaseProof
+        );
+
+        self.purchase_record.set_inner(PurchaseRecord {
+            campaign: self.campaign.key(),
+            review: self.review_account.key(),
+            reviewer: self.reviewer.key(),
+            bump: bumps.purchase_record,
+        });
+
+        // Bond is discounted by the reviewer's rank at the time of the review
+        let bond = self.campaign.bond_for_rank(self.reviewer_account.rank)?;
+
         self.review_account.set_inner(ReviewAccount {
+            version: REVIEW_ACCOUNT_VERSION,
             campaign_id: self.campaign.key(),
             reviewer: self.reviewer.key(),
             description,
@@ -90,13 +156,92 @@ impl<'info> MakeReview<'info> {
             tx_id,
             reviewer_rank: self.reviewer_account.rank,
             timestamp: current_time,
+            vesting_total: 0,
+            vesting_withdrawn: 0,
+            vesting_start: 0,
+            bond,
+            bond_bump: bumps.bond_vault,
             bump: bumps.review_account,
+            passing_attestations: 0,
+            failing_attestations: 0,
         });
 
+        if bond > 0 {
+            self.post_bond(bond)?;
+        }
+
+        if self.platform.is_sponsored(self.payer.key(), self.reviewer.key())? {
+            self.reimburse_sponsor()?;
+        }
+
+        self.campaign.pending_reviews = self.campaign.pending_reviews
+            .checked_add(1)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
         self.platform.total_reviews = self.platform.total_reviews
             .checked_add(1)
             .ok_or(PlatformError::ArithmeticOverflow)?;
 
         Ok(())
     }
-}
+
+    // The campaign's rent budget pays the sponsor back now, the rest becomes reviewer debt
+    pub fn reimburse_sponsor(&mut self) -> Result<()> {
+        let rent = self.review_account.to_account_info().lamports()
+            .checked_add(self.purchase_record.to_account_info().lamports())
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let from_budget = std::cmp::min(rent, self.campaign.rent_budget);
+
+        if from_budget > 0 {
+            let campaign_key = self.campaign.key();
+            let seeds = &[
+                &b"campaign_vault"[..],
+                campaign_key.as_ref(),
+                &[self.campaign.vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: self.vault.to_account_info(),
+                to: self.payer.to_account_info(),
+            };
+
+            let ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+
+            transfer(ctx, from_budget)?;
+
+            self.campaign.rent_budget -= from_budget;
+        }
+
+        self.reviewer_account.rent_debt = self.reviewer_account.rent_debt
+            .checked_add(rent - from_budget)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn post_bond(&self, bond: u64) -> Result<()> {
+        // The vault also needs its rent-exempt minimum, which goes back to the reviewer on settlement
+        let rent_exempt: u64 = Rent::get()?.minimum_balance(self.bond_vault.to_account_info().data_len());
+
+        let amount = bond
+            .checked_add(rent_exempt)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let cpi_accounts = Transfer {
+            from: self.reviewer.to_account_info(),
+            to: self.bond_vault.to_account_info(),
+        };
+
+        let ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(ctx, amount)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
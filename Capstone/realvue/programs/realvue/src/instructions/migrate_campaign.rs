@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::PlatformError,
+    migration::{load_any_version, realloc_to, ReviewAccountV0, ReviewCampaignV0},
+    Category, PlatformConfig, ReviewAccount, ReviewCampaign, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct MigrateCampaign<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: deserialized by hand, the old layout may not fit the new struct
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+
+    // Campaigns from before v8 need the platform they were created under, see backfill_platform
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Option<Account<'info, PlatformConfig>>,
+
+    // Only needed for categorized campaigns from before v8
+    pub category: Option<Account<'info, Category>>,
+
+    // Uncategorized campaigns from before v8 need both sides to agree on the platform
+    pub merchant: Option<Signer<'info>>,
+    pub admin: Option<Signer<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateCampaign<'info> {
+    // Permissionless, the payer only covers the extra rent. Campaigns from before v2 also
+    // take every one of their reviews, the counters added in v2 are rebuilt from them.
+    pub fn migrate_campaign(&mut self, reviews: &[AccountInfo<'info>]) -> Result<()> {
+
+        let info = self.campaign.to_account_info();
+        let mut campaign: ReviewCampaign =
+            load_any_version::<ReviewCampaign, ReviewCampaignV0>(&info.try_borrow_data()?)?;
+
+        require!(
+            campaign.version <= REVIEW_CAMPAIGN_VERSION,
+            PlatformError::UnsupportedAccountVersion
+        );
+
+        // v0 -> v1: rewards were priced with the platform fee at claim time, snapshot it now
+        if campaign.version == 0 {
+            let Some(platform) = &self.platform else {
+                return err!(PlatformError::CampaignPlatformMismatch);
+            };
+            campaign.fee_bps = platform.platform_fee;
+        }
+
+        // v1 -> v2: reserved_for_reviewers needs the pending and claimed counts
+        if campaign.version < 2 {
+            self.backfill_review_counters(&mut campaign, reviews)?;
+        }
+
+        // v5 -> v6: campaign_id stays 0, so the campaign keeps its name-based address
+
+        // v7 -> v8: campaign-scoped instructions check the platform against this
+        if campaign.version < 8 {
+            self.backfill_platform(&mut campaign)?;
+        }
+
+        realloc_to(
+            &self.campaign.to_account_info(),
+            &self.payer.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + ReviewCampaign::INIT_SPACE,
+        )?;
+
+        campaign.version = REVIEW_CAMPAIGN_VERSION;
+
+        campaign.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+
+    pub fn backfill_review_counters(
+        &self,
+        campaign: &mut ReviewCampaign,
+        reviews: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let campaign_key = self.campaign.key();
+
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(reviews.len());
+        let mut approved: u16 = 0;
+        let mut claimed: u16 = 0;
+        let mut pending: u16 = 0;
+
+        for info in reviews {
+            require!(
+                info.owner == &crate::ID && !seen.contains(info.key),
+                PlatformError::MigrationReviewMismatch
+            );
+            seen.push(info.key());
+
+            let review: ReviewAccount =
+                load_any_version::<ReviewAccount, ReviewAccountV0>(&info.try_borrow_data()?)?;
+
+            // Only this campaign's review PDAs count
+            let address = Pubkey::create_program_address(
+                &[campaign_key.as_ref(), review.reviewer.as_ref(), &[review.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| error!(PlatformError::MigrationReviewMismatch))?;
+
+            require!(
+                address == info.key(),
+                PlatformError::MigrationReviewMismatch
+            );
+
+            // Clawbacks before v2 left approved_count as it was
+            if review.approved || review.is_clawed_back() {
+                approved = approved.checked_add(1).ok_or(PlatformError::ArithmeticOverflow)?;
+
+                if review.reward_claimed {
+                    claimed = claimed.checked_add(1).ok_or(PlatformError::ArithmeticOverflow)?;
+                }
+            } else if review.flagged_reason.is_empty() {
+                pending = pending.checked_add(1).ok_or(PlatformError::ArithmeticOverflow)?;
+            }
+        }
+
+        // Every approved review has to be there, otherwise claimed rewards would be reserved again
+        require!(
+            approved == campaign.approved_count,
+            PlatformError::MigrationReviewMismatch
+        );
+
+        campaign.pending_reviews = pending;
+        campaign.rewards_claimed = claimed;
+
+        Ok(())
+    }
+
+    // Categories record their platform. Uncategorized campaigns have nothing on chain that
+    // names one, so the merchant and that platform's admin both sign for it.
+    pub fn backfill_platform(&self, campaign: &mut ReviewCampaign) -> Result<()> {
+        let Some(platform) = &self.platform else {
+            return err!(PlatformError::CampaignPlatformMismatch);
+        };
+
+        if campaign.category != Pubkey::default() {
+            let Some(category) = &self.category else {
+                return err!(PlatformError::InvalidCategory);
+            };
+
+            require_keys_eq!(category.key(), campaign.category, PlatformError::InvalidCategory);
+            require_keys_eq!(category.platform, platform.key(), PlatformError::CampaignPlatformMismatch);
+        } else {
+            require!(
+                self.merchant.as_ref().is_some_and(|merchant| merchant.key() == campaign.merchant),
+                PlatformError::UnauthorizedMerchant
+            );
+
+            require!(
+                self.admin.as_ref().is_some_and(|admin| admin.key() == platform.admin),
+                PlatformError::UnauthorizedAdmin
+            );
+        }
+
+        campaign.platform = platform.key();
+
+        Ok(())
+    }
+}
\ No newline at end of file
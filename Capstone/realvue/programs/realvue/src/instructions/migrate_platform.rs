@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, migration::{load_any_version, realloc_to, PlatformConfigV0}, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct MigratePlatform<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: deserialized by hand, the old layout may not fit the new struct
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub platform: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigratePlatform<'info> {
+    pub fn migrate_platform(&mut self) -> Result<()> {
+
+        let info = self.platform.to_account_info();
+        let mut platform: PlatformConfig =
+            load_any_version::<PlatformConfig, PlatformConfigV0>(&info.try_borrow_data()?)?;
+
+        // Only the platform admin can migrate the platform
+        require!(
+            platform.admin == self.admin.key(),
+            PlatformError::UnauthorizedAdmin
+        );
+
+        require!(
+            platform.version <= PLATFORM_CONFIG_VERSION,
+            PlatformError::UnsupportedAccountVersion
+        );
+
+        // Every field added since v0 defaults to zero, nothing to backfill
+        realloc_to(
+            &self.platform.to_account_info(),
+            &self.admin.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + PlatformConfig::INIT_SPACE,
+        )?;
+
+        platform.version = PLATFORM_CONFIG_VERSION;
+
+        platform.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, migration::{load_any_version, realloc_to, ReviewAccountV0}, ReviewAccount, REVIEW_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct MigrateReview<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: deserialized by hand, the old layout may not fit the new struct
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub review_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateReview<'info> {
+    // Permissionless, the payer only covers the extra rent
+    pub fn migrate_review(&mut self) -> Result<()> {
+
+        let info = self.review_account.to_account_info();
+        let mut review: ReviewAccount =
+            load_any_version::<ReviewAccount, ReviewAccountV0>(&info.try_borrow_data()?)?;
+
+        require!(
+            review.version <= REVIEW_ACCOUNT_VERSION,
+            PlatformError::UnsupportedAccountVersion
+        );
+
+        // Attestation counts start at zero, nothing to backfill
+        realloc_to(
+            &self.review_account.to_account_info(),
+            &self.payer.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + ReviewAccount::INIT_SPACE,
+        )?;
+
+        review.version = REVIEW_ACCOUNT_VERSION;
+
+        review.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
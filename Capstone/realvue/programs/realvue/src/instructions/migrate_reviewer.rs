@@ -0,0 +1,54 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{migration::ReviewerAccountV0, ReviewerAccount};
+
+#[derive(Accounts)]
+pub struct MigrateReviewer<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    /// CHECK: first deployment layout, loaded by hand
+    #[account(
+        mut,
+        seeds = [b"reviewer", reviewer.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub legacy_reviewer_account: UncheckedAccount<'info>,
+
+    // The platform scoped account to carry the reputation into, left out to only close
+    #[account(
+        mut,
+        has_one = reviewer,
+        seeds = [b"reviewer", reviewer_account.platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Option<Account<'info, ReviewerAccount>>,
+}
+
+impl<'info> MigrateReviewer<'info> {
+    pub fn migrate_reviewer(&mut self) -> Result<()> {
+
+        let legacy = ReviewerAccountV0::load(&self.legacy_reviewer_account.try_borrow_data()?)?;
+
+        // Take the better record like import_reputation, the legacy account is closed below so it only counts once
+        if let Some(reviewer_account) = self.reviewer_account.as_mut() {
+            let approved_count = std::cmp::max(reviewer_account.approved_count, legacy.approved_count);
+
+            reviewer_account.approved_count = approved_count;
+            reviewer_account.rank = ReviewerAccount::rank_for(approved_count);
+        }
+
+        // Legacy accounts were always paid for by the reviewer, so the rent goes back to them
+        let info = self.legacy_reviewer_account.to_account_info();
+        let rent = info.lamports();
+
+        info.sub_lamports(rent)?;
+        self.reviewer.add_lamports(rent)?;
+
+        info.assign(&system_program::ID);
+        info.realloc(0, false)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
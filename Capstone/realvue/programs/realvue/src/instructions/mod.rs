@@ -9,6 +9,43 @@ pub mod close_campaign;
 pub mod refund_deposit;
 pub mod close_reviewer;
 pub mod close_platform;
+pub mod set_fee_tiers;
+pub mod set_referral_share;
+pub mod register_referrer;
+pub mod claim_referral;
+pub mod set_withdrawal_timelock;
+pub mod request_withdrawal;
+pub mod execute_withdrawal;
+pub mod cancel_withdrawal;
+pub mod withdraw_vested;
+pub mod clawback_reward;
+pub mod set_review_bond;
+pub mod set_trusted_platforms;
+pub mod import_reputation;
+pub mod migrate_platform;
+pub mod migrate_campaign;
+pub mod migrate_review;
+pub mod migrate_reviewer;
+pub mod set_attestors;
+pub mod attest_review;
+pub mod sync_rank_badge;
+pub mod set_cancellation_policy;
+pub mod cancel_campaign;
+pub mod claim_kill_fee;
+pub mod enable_compression;
+pub mod make_compressed_review;
+pub mod approve_compressed_review;
+pub mod claim_compressed_reward;
+pub mod create_category;
+pub mod update_category;
+pub mod reply_to_review;
+pub mod edit_reply;
+pub mod close_reply;
+pub mod moderate_reply;
+pub mod set_review_sponsor;
+pub mod fund_rent_budget;
+pub mod rename_campaign;
+pub mod set_rev_payout;
 
 pub use init_platform::*;
 pub use create_campaign::*;
@@ -20,4 +57,41 @@ pub use claim_fee::*;
 pub use close_campaign::*;
 pub use refund_deposit::*;
 pub use close_reviewer::*;
-pub use close_platform::*;
\ No newline at end of file
+pub use close_platform::*;
+pub use set_fee_tiers::*;
+pub use set_referral_share::*;
+pub use register_referrer::*;
+pub use claim_referral::*;
+pub use set_withdrawal_timelock::*;
+pub use request_withdrawal::*;
+pub use execute_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use withdraw_vested::*;
+pub use clawback_reward::*;
+pub use set_review_bond::*;
+pub use set_trusted_platforms::*;
+pub use import_reputation::*;
+pub use migrate_platform::*;
+pub use migrate_campaign::*;
+pub use migrate_review::*;
+pub use migrate_reviewer::*;
+pub use set_attestors::*;
+pub use attest_review::*;
+pub use sync_rank_badge::*;
+pub use set_cancellation_policy::*;
+pub use cancel_campaign::*;
+pub use claim_kill_fee::*;
+pub use enable_compression::*;
+pub use make_compressed_review::*;
+pub use approve_compressed_review::*;
+pub use claim_compressed_reward::*;
+pub use create_category::*;
+pub use update_category::*;
+pub use reply_to_review::*;
+pub use edit_reply::*;
+pub use close_reply::*;
+pub use moderate_reply::*;
+pub use set_review_sponsor::*;
+pub use fund_rent_budget::*;
+pub use rename_campaign::*;
+pub use set_rev_payout::*;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewReply, ReviewReplyUpdated, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct ModerateReply<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = platform,
+        seeds = [b"reply", reply.review.as_ref()],
+        bump = reply.bump
+    )]
+    pub reply: Account<'info, ReviewReply>,
+
+    #[account(
+        has_one = admin, // Ensure only the platform admin can moderate
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+}
+
+impl<'info> ModerateReply<'info> {
+    pub fn moderate_reply(&mut self, hidden: bool) -> Result<()> {
+
+        self.reply.hidden = hidden;
+
+        emit!(ReviewReplyUpdated::from_reply(self.reply.key(), &self.reply, false));
+
+        Ok(())
+    }
+}
\ No newline at end of file
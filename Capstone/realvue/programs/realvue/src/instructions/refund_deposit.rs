@@ -3,7 +3,7 @@ use anchor_lang::system_program::{transfer, Transfer};
 
 use crate::{
     errors::PlatformError,
-    state::{PlatformConfig, ReviewCampaign},
+    state::{PlatformConfig, ReviewCampaign, PLATFORM_CONFIG_VERSION, REVIEW_CAMPAIGN_VERSION},
 };
 
 #[derive(Accounts)]
@@ -13,9 +13,10 @@ pub struct RefundDeposit<'info> {
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign.name.as_str().as_bytes(), campaign.merchant.key().as_ref()],
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
         bump = campaign.bump,
-        close = merchant
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
     )]
     pub campaign: Account<'info, ReviewCampaign>,
 
@@ -30,6 +31,7 @@ pub struct RefundDeposit<'info> {
         mut,
         seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
         bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
     )]
     pub platform: Account<'info, PlatformConfig>,
 
@@ -44,30 +46,64 @@ impl<'info> RefundDeposit<'info> {
             PlatformError::UnauthorizedMerchant
         );
 
-        // Check if campaign is not active (active: false)
-        require!(self.campaign.active, PlatformError::CampaignNotActive);
-
-        // Check if approved_count < reviews_needed
-        require!(
-            self.campaign.approved_count < self.campaign.reviews_needed,
-            PlatformError::CampaignTargetMet
-        );
+        if self.campaign.cancelled {
+            // Moderators resolve pending reviews first, the rest are owed a kill fee
+            let current_time = Clock::get()?.unix_timestamp;
+            require!(
+                current_time >= self.campaign.grace_ends_at,
+                PlatformError::GracePeriodActive
+            );
+        } else if !self.campaign.refunded {
+            // Check if campaign is not active (active: false)
+            require!(self.campaign.active, PlatformError::CampaignNotActive);
+
+            // Pending reviewers would get nothing, cancel_campaign settles them first
+            require!(
+                self.campaign.pending_reviews == 0,
+                PlatformError::ReviewsPending
+            );
+
+            // Check if approved_count < reviews_needed, rewards taken as REV are refundable either way
+            require!(
+                self.campaign.approved_count < self.campaign.reviews_needed
+                    || self.campaign.rev_paid_rewards > 0,
+                PlatformError::CampaignTargetMet
+            );
+        }
 
-        let vault_balance = self.vault.lamports();
+        // Rewards still vesting or owed to reviewers are not refundable
+        let reserved = self.campaign.reserved_for_reviewers()?;
+        let vault_balance = self.campaign
+            .refundable_balance(self.vault.lamports())
+            .saturating_sub(reserved);
 
         // Calculate refundable amount
         let refundable_amount = self.calculate_refundable_amount(vault_balance)?;
 
-        require!(refundable_amount > 0, PlatformError::NoRefundAvailable);
+        // Once refunded, calling again only closes the campaign after reviewers are paid out
+        let closable = self.campaign.vesting_locked == 0 && reserved == 0;
+
+        require!(
+            refundable_amount > 0 || (self.campaign.refunded && closable),
+            PlatformError::NoRefundAvailable
+        );
 
         // Transfer refund to merchant
-        self.transfer_refund_to_merchant(refundable_amount)?;
+        if refundable_amount > 0 {
+            self.transfer_refund_to_merchant(refundable_amount)?;
+        }
 
-        // Mark campaign as refunded
+        // Mark campaign as refunded, an unspent rent budget went back with the refund
         self.campaign.refunded = true;
+        self.campaign.rent_budget = 0;
 
         self.campaign.active = false;
 
+        // Reviewers still need the campaign to claim or withdraw, so it stays open until they are done
+        if closable {
+            self.campaign.close(self.merchant.to_account_info())?;
+        }
+
         Ok(())
     }
 
@@ -75,16 +111,14 @@ impl<'info> RefundDeposit<'info> {
 
         // If no reviews were approved, refund everything except platform fee
         if self.campaign.approved_count == 0 {
-            let fee_amount = (self.campaign.deposit_amount as u128)
-                .checked_mul(self.platform.platform_fee as u128)
-                .unwrap()
-                .checked_div(10000)
-                .unwrap() as u64;
+            let fee_amount = self.campaign.fee_amount()?;
 
             let expected_refund = self
                 .campaign
                 .deposit_amount
                 .checked_sub(fee_amount)
+                .ok_or(PlatformError::ArithmeticOverflow)?
+                .checked_add(self.campaign.rent_budget)
                 .ok_or(PlatformError::ArithmeticOverflow)?;
 
             return Ok(std::cmp::min(expected_refund, vault_balance));
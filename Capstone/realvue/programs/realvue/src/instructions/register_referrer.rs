@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReferrerAccount, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        seeds = [b"referrer", platform.key().as_ref(), referrer.key().as_ref()],
+        bump,
+        space = 8 + ReferrerAccount::INIT_SPACE
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterReferrer<'info> {
+    pub fn register_referrer(&mut self, bumps: &RegisterReferrerBumps) -> Result<()> {
+
+        require!(
+            self.platform.is_active,
+            PlatformError::PlatformInactive
+        );
+
+        self.referrer_account.set_inner(ReferrerAccount {
+            referrer: self.referrer.key(),
+            platform: self.platform.key(),
+            pending_rewards: 0,
+            total_earned: 0,
+            total_campaigns: 0,
+            bump: bumps.referrer_account,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
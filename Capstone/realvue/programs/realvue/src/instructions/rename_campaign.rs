@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, ReviewCampaign, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct RenameCampaign<'info> {
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+}
+
+impl<'info> RenameCampaign<'info> {
+    pub fn rename_campaign(&mut self, name: String) -> Result<()> {
+
+        // The name is still part of the address of campaigns without an id
+        require!(
+            self.campaign.campaign_id != 0,
+            PlatformError::CampaignNameImmutable
+        );
+
+        ReviewCampaign::validate_name(&name)?;
+
+        self.campaign.name = name;
+
+        Ok(())
+    }
+}
\ No newline at end of file
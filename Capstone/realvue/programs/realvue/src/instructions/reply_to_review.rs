@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewReply, ReviewReplyUpdated, PLATFORM_CONFIG_VERSION, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct ReplyToReview<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        init,
+        payer = merchant,
+        seeds = [b"reply", review_account.key().as_ref()],
+        bump,
+        space = 8 + ReviewReply::INIT_SPACE
+    )]
+    pub reply: Account<'info, ReviewReply>,
+
+    #[account(
+        seeds = [campaign.key().as_ref(), review_account.reviewer.key().as_ref()],
+        bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub review_account: Account<'info, ReviewAccount>,
+
+    #[account(
+        has_one = merchant, // Only the campaign's merchant can reply
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+        has_one = platform @ PlatformError::CampaignPlatformMismatch,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReplyToReview<'info> {
+    pub fn reply_to_review(&mut self, body: String, bumps: &ReplyToReviewBumps) -> Result<()> {
+
+        require!(
+            self.platform.is_active,
+            PlatformError::PlatformInactive
+        );
+
+        ReviewReply::validate_body(&body)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.reply.set_inner(ReviewReply {
+            platform: self.platform.key(),
+            campaign: self.campaign.key(),
+            review: self.review_account.key(),
+            merchant: self.merchant.key(),
+            body,
+            hidden: false,
+            created_at: current_time,
+            updated_at: current_time,
+            bump: bumps.reply,
+        });
+
+        emit!(ReviewReplyUpdated::from_reply(self.reply.key(), &self.reply, false));
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PendingWithdrawal, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"treasury", platform.key().as_ref()],
+        bump = platform.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestWithdrawal<'info> {
+    pub fn request_withdrawal(&mut self, amount: u64, destination: Pubkey) -> Result<()> {
+
+        require!(
+            self.platform.pending_withdrawal.is_none(),
+            PlatformError::WithdrawalAlreadyPending
+        );
+
+        require!(
+            amount > 0,
+            PlatformError::InvalidWithdrawalAmount
+        );
+
+        // Fail early, the balance is checked again on execution
+        let rent_exempt: u64 = Rent::get()?.minimum_balance(self.treasury.to_account_info().data_len());
+        let withdrawable = self.treasury.lamports().saturating_sub(rent_exempt);
+
+        require!(
+            amount <= withdrawable,
+            PlatformError::InsufficientTreasuryFunds
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        self.platform.apply_pending_timelock(current_time);
+
+        let executable_at = current_time
+            .checked_add(self.platform.withdrawal_timelock)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        self.platform.pending_withdrawal = Some(PendingWithdrawal {
+            amount,
+            destination,
+            executable_at,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, MAX_ATTESTORS, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetAttestors<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAttestors<'info> {
+    pub fn set_attestors(&mut self, attestors: Vec<Pubkey>, attestation_threshold: u8) -> Result<()> {
+
+        require!(
+            attestors.len() <= MAX_ATTESTORS,
+            PlatformError::TooManyAttestors
+        );
+
+        // Duplicate keys would let one attestor count twice towards K
+        let unique = attestors
+            .iter()
+            .enumerate()
+            .all(|(i, key)| !attestors[..i].contains(key));
+
+        require!(
+            unique,
+            PlatformError::DuplicateAttestor
+        );
+
+        // K of N, K can't exceed N
+        require!(
+            attestation_threshold as usize <= attestors.len(),
+            PlatformError::InvalidAttestationThreshold
+        );
+
+        self.platform.attestors = attestors;
+        self.platform.attestation_threshold = attestation_threshold;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetCancellationPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetCancellationPolicy<'info> {
+    pub fn set_cancellation_policy(&mut self, grace_period: i64, kill_fee_bps: u16) -> Result<()> {
+
+        require!(
+            grace_period >= 0,
+            PlatformError::InvalidCancellationPolicy
+        );
+
+        // Kill fee is a share of the per-review reward, at most the full reward
+        require!(
+            kill_fee_bps <= 10000,
+            PlatformError::InvalidCancellationPolicy
+        );
+
+        // Campaigns already cancelled keep the policy they snapshotted
+        self.platform.cancellation_grace_period = grace_period;
+        self.platform.kill_fee_bps = kill_fee_bps;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, FeeTier, PlatformConfig, MAX_FEE_TIERS, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetFeeTiers<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFeeTiers<'info> {
+    pub fn set_fee_tiers(&mut self, fee_tiers: Vec<FeeTier>) -> Result<()> {
+
+        require!(
+            fee_tiers.len() <= MAX_FEE_TIERS,
+            PlatformError::InvalidFeeTiers
+        );
+
+        // Same limit as init_platform (max 10% = 1000 basis points)
+        require!(
+            fee_tiers.iter().all(|tier| tier.fee_bps <= 1000),
+            PlatformError::InvalidFeePercentage
+        );
+
+        // Tiers are looked up by volume, so they must be strictly ascending
+        require!(
+            fee_tiers.windows(2).all(|pair| pair[0].min_volume < pair[1].min_volume),
+            PlatformError::InvalidFeeTiers
+        );
+
+        self.platform.fee_tiers = fee_tiers;
+
+        Ok(())
+    }
+}
\ No newline at end of file
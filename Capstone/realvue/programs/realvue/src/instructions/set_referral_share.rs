@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetReferralShare<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReferralShare<'info> {
+    pub fn set_referral_share(&mut self, referral_share_bps: u16) -> Result<()> {
+
+        // Share of the fee, so at most the whole fee (10000 basis points)
+        require!(
+            referral_share_bps <= 10000,
+            PlatformError::InvalidReferralShare
+        );
+
+        self.platform.referral_share_bps = referral_share_bps;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetRevPayout<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRevPayout<'info> {
+    pub fn set_rev_payout(&mut self, premium_bps: Option<u16>) -> Result<()> {
+
+        // At most double the REV a reward is worth at par
+        if let Some(premium_bps) = premium_bps {
+            require!(
+                premium_bps <= 10000,
+                PlatformError::InvalidRevPremium
+            );
+        }
+
+        self.platform.rev_payout_premium_bps = premium_bps;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, BondSlashTarget, ReviewCampaign, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct SetReviewBond<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = merchant,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReviewBond<'info> {
+    pub fn set_review_bond(&mut self, bond_amount: u64, bond_slash_target: BondSlashTarget) -> Result<()> {
+
+        require!(
+            self.campaign.active,
+            PlatformError::CampaignNotActive
+        );
+
+        // Compressed reviews have no PDA to hold a bond vault against
+        require!(
+            self.campaign.merkle_tree.is_none(),
+            PlatformError::CompressionUnsupported
+        );
+
+        // Reviews keep the bond they posted, this only affects new reviews
+        self.campaign.bond_amount = bond_amount;
+        self.campaign.bond_slash_target = bond_slash_target;
+
+        Ok(())
+    }
+}
\ No newline at end of file
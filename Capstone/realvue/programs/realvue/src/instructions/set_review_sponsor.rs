@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetReviewSponsor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReviewSponsor<'info> {
+    pub fn set_review_sponsor(&mut self, sponsor: Option<Pubkey>) -> Result<()> {
+
+        // Debt already owed is repaid to whoever sponsors when the reward is claimed
+        self.platform.review_sponsor = sponsor;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PlatformConfig, MAX_TRUSTED_PLATFORMS, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetTrustedPlatforms<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetTrustedPlatforms<'info> {
+    pub fn set_trusted_platforms(&mut self, trusted_platforms: Vec<Pubkey>) -> Result<()> {
+
+        require!(
+            trusted_platforms.len() <= MAX_TRUSTED_PLATFORMS,
+            PlatformError::TooManyTrustedPlatforms
+        );
+
+        // A platform trusting itself would make imports a no-op loop
+        require!(
+            !trusted_platforms.contains(&self.platform.key()),
+            PlatformError::UntrustedPlatform
+        );
+
+        self.platform.trusted_platforms = trusted_platforms;
+
+        Ok(())
+    }
+}
\ No newline at end of file
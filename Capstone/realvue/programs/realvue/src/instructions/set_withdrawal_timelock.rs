@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, PendingTimelock, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct SetWithdrawalTimelock<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWithdrawalTimelock<'info> {
+    pub fn set_withdrawal_timelock(&mut self, timelock: i64) -> Result<()> {
+
+        require!(
+            timelock >= 0,
+            PlatformError::InvalidTimelock
+        );
+
+        // Changing the delay must not shorten a withdrawal already in flight
+        require!(
+            self.platform.pending_withdrawal.is_none(),
+            PlatformError::WithdrawalAlreadyPending
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        self.platform.apply_pending_timelock(current_time);
+
+        if timelock >= self.platform.withdrawal_timelock {
+            // A longer delay only protects more, it applies at once and drops any queued decrease
+            self.platform.withdrawal_timelock = timelock;
+            self.platform.pending_timelock = None;
+        } else {
+            // A shorter delay waits out the current one, like a withdrawal would
+            let effective_at = current_time
+                .checked_add(self.platform.withdrawal_timelock)
+                .ok_or(PlatformError::ArithmeticOverflow)?;
+
+            self.platform.pending_timelock = Some(PendingTimelock {
+                timelock,
+                effective_at,
+            });
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
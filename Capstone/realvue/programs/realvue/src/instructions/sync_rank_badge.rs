@@ -0,0 +1,267 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address_with_program_id, AssociatedToken, Create},
+    token_2022::Token2022,
+    token_interface::{
+        initialize_mint2, metadata_pointer_initialize, mint_to, non_transferable_mint_initialize,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_2022::{extension::ExtensionType, state::Mint as MintState},
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, InitializeMint2,
+        MetadataPointerInitialize, MintTo, NonTransferableMintInitialize, TokenMetadataInitialize,
+        TokenMetadataUpdateField,
+    },
+};
+
+use crate::{errors::PlatformError, PlatformConfig, ReviewerAccount, PLATFORM_CONFIG_VERSION};
+
+pub const BADGE_SYMBOL: &str = "RVBADGE";
+
+// Mints the reviewer's soulbound rank badge, or upgrades its metadata to the current rank.
+// Kept out of approve_review so moderation doesn't carry the Token-2022 accounts,
+// clients append it after any instruction that changes the rank.
+#[derive(Accounts)]
+pub struct SyncRankBadge<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    #[account(
+        address = reviewer_account.reviewer
+    )]
+    pub reviewer: SystemAccount<'info>,
+
+    /// CHECK: Token-2022 mint created here with NonTransferable + metadata extensions
+    #[account(
+        mut,
+        seeds = [b"badge", reviewer_account.key().as_ref()],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: reviewer's badge token account, created idempotently by the ATA program
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&reviewer.key(), &badge_mint.key(), &token_program.key())
+    )]
+    pub badge_ata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SyncRankBadge<'info> {
+    pub fn sync_rank_badge(&mut self, bumps: &SyncRankBadgeBumps) -> Result<()> {
+
+        let rank = self.reviewer_account.rank;
+
+        require!(
+            rank != self.reviewer_account.badge_rank,
+            PlatformError::BadgeUpToDate
+        );
+
+        let name = format!("Realvue {} Reviewer", ReviewerAccount::rank_name(rank));
+
+        if self.badge_mint.data_is_empty() {
+            self.create_badge_mint(name, bumps)?;
+            self.mint_badge()?;
+        } else {
+            self.update_badge_field(Field::Name, name)?;
+            self.update_badge_field(
+                Field::Key("rank".to_string()),
+                ReviewerAccount::rank_name(rank).to_string(),
+            )?;
+        }
+
+        self.reviewer_account.badge_rank = rank;
+
+        Ok(())
+    }
+
+    pub fn create_badge_mint(&self, name: String, bumps: &SyncRankBadgeBumps) -> Result<()> {
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+        ])?;
+
+        // Fund the metadata for the longest rank name up front, upgrades then never need a top-up
+        let longest = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(self.platform.key()),
+            mint: self.badge_mint.key(),
+            name: format!("Realvue {} Reviewer", ReviewerAccount::rank_name(4)),
+            symbol: BADGE_SYMBOL.to_string(),
+            uri: String::new(),
+            additional_metadata: vec![("rank".to_string(), ReviewerAccount::rank_name(4).to_string())],
+        };
+        let metadata_space = longest.tlv_size_of()?;
+
+        let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+
+        let reviewer_account_key = self.reviewer_account.key();
+        let badge_seeds = &[
+            &b"badge"[..],
+            reviewer_account_key.as_ref(),
+            &[bumps.badge_mint],
+        ];
+        let badge_signer_seeds = &[&badge_seeds[..]];
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            CreateAccount {
+                from: self.payer.to_account_info(),
+                to: self.badge_mint.to_account_info(),
+            },
+            badge_signer_seeds,
+        );
+
+        create_account(ctx, lamports, space as u64, &self.token_program.key())?;
+
+        // Extensions must be initialized before the mint itself
+        non_transferable_mint_initialize(CpiContext::new(
+            self.token_program.to_account_info(),
+            NonTransferableMintInitialize {
+                token_program_id: self.token_program.to_account_info(),
+                mint: self.badge_mint.to_account_info(),
+            },
+        ))?;
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.badge_mint.to_account_info(),
+                },
+            ),
+            Some(self.platform.key()),
+            Some(self.badge_mint.key()),
+        )?;
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.badge_mint.to_account_info(),
+                },
+            ),
+            0,
+            &self.platform.key(),
+            Some(&self.platform.key()),
+        )?;
+
+        let admin_key = self.platform.admin.key();
+        let seed = self.platform.seed.to_le_bytes();
+        let seeds = &[
+            &b"realvue"[..],
+            seed.as_ref(),
+            admin_key.as_ref(),
+            &[self.platform.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    program_id: self.token_program.to_account_info(),
+                    metadata: self.badge_mint.to_account_info(),
+                    update_authority: self.platform.to_account_info(),
+                    mint_authority: self.platform.to_account_info(),
+                    mint: self.badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            name,
+            BADGE_SYMBOL.to_string(),
+            String::new(),
+        )?;
+
+        self.update_badge_field(
+            Field::Key("rank".to_string()),
+            ReviewerAccount::rank_name(self.reviewer_account.rank).to_string(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn mint_badge(&self) -> Result<()> {
+        create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            Create {
+                payer: self.payer.to_account_info(),
+                associated_token: self.badge_ata.to_account_info(),
+                authority: self.reviewer.to_account_info(),
+                mint: self.badge_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        let admin_key = self.platform.admin.key();
+        let seed = self.platform.seed.to_le_bytes();
+        let seeds = &[
+            &b"realvue"[..],
+            seed.as_ref(),
+            admin_key.as_ref(),
+            &[self.platform.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: self.badge_mint.to_account_info(),
+            to: self.badge_ata.to_account_info(),
+            authority: self.platform.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        mint_to(ctx, 1)?;
+
+        Ok(())
+    }
+
+    pub fn update_badge_field(&self, field: Field, value: String) -> Result<()> {
+        let admin_key = self.platform.admin.key();
+        let seed = self.platform.seed.to_le_bytes();
+        let seeds = &[
+            &b"realvue"[..],
+            seed.as_ref(),
+            admin_key.as_ref(),
+            &[self.platform.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: self.token_program.to_account_info(),
+                    metadata: self.badge_mint.to_account_info(),
+                    update_authority: self.platform.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            field,
+            value,
+        )?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
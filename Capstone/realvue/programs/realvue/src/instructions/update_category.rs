@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::PlatformError, Category, CategoryPolicy, PlatformConfig, PLATFORM_CONFIG_VERSION};
+
+#[derive(Accounts)]
+pub struct UpdateCategory<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = platform,
+        seeds = [b"category", platform.key().as_ref(), category.name.as_bytes()],
+        bump = category.bump
+    )]
+    pub category: Account<'info, Category>,
+
+    #[account(
+        has_one = admin,
+        seeds = [b"realvue", platform.seed.to_le_bytes().as_ref(), platform.admin.key().as_ref()],
+        bump = platform.bump,
+        constraint = platform.version == PLATFORM_CONFIG_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateCategory<'info> {
+    pub fn update_category(&mut self, policy: CategoryPolicy, active: bool) -> Result<()> {
+
+        policy.validate()?;
+
+        // Existing campaigns keep the fee they snapshotted, review limits apply from now on
+        self.category.policy = policy;
+        self.category.active = active;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{errors::PlatformError, ReviewAccount, ReviewCampaign, ReviewerAccount, REVIEW_ACCOUNT_VERSION, REVIEW_CAMPAIGN_VERSION};
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = reviewer,
+        seeds = [campaign.key().as_ref(), review_account.reviewer.key().as_ref()],
+        bump = review_account.bump,
+        constraint = review_account.version == REVIEW_ACCOUNT_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub review_account: Account<'info, ReviewAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.address_seeds()[0].as_ref(), campaign.address_seeds()[1].as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == REVIEW_CAMPAIGN_VERSION @ PlatformError::UnsupportedAccountVersion,
+    )]
+    pub campaign: Account<'info, ReviewCampaign>,
+
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reviewer", reviewer_account.platform.key().as_ref(), reviewer.key().as_ref()],
+        bump = reviewer_account.bump
+    )]
+    pub reviewer_account: Account<'info, ReviewerAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawVested<'info> {
+    pub fn withdraw_vested(&mut self) -> Result<()> {
+
+        let schedule = self
+            .campaign
+            .vesting
+            .ok_or(PlatformError::VestingNotEnabled)?;
+
+        require!(
+            self.review_account.reward_claimed,
+            PlatformError::ReviewNotApproved
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Clawed-back reviews were cut down to what had vested, so no schedule applies
+        let vested = if self.review_account.is_clawed_back() {
+            self.review_account.vesting_total
+        } else {
+            schedule.vested_amount(
+                self.review_account.vesting_total,
+                self.review_account.vesting_start,
+                current_time,
+            )?
+        };
+
+        let amount = vested
+            .checked_sub(self.review_account.vesting_withdrawn)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        require!(
+            amount > 0,
+            PlatformError::NothingVested
+        );
+
+        let campaign_key = self.campaign.key();
+        let seeds = &[
+            &b"campaign_vault"[..],
+            campaign_key.as_ref(),
+            &[self.campaign.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.reviewer.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, amount)?;
+
+        self.review_account.vesting_withdrawn = vested;
+
+        self.campaign.vesting_locked = self.campaign.vesting_locked
+            .checked_sub(amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        // Update reviewer account stats
+        self.reviewer_account.total_earned = self.reviewer_account.total_earned
+            .checked_add(amount)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
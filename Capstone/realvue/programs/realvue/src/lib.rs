@@ -2,10 +2,14 @@
 
 use anchor_lang::prelude::*;
 
+pub mod compression;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod migration;
 pub mod state;
 
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -19,6 +23,7 @@ pub mod realvue {
         ctx.accounts.init_platform(seed, platform_fee, &ctx.bumps)?;
         Ok(())
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         name: String,
@@ -27,6 +32,7 @@ pub mod realvue {
         start_time: i64,
         end_time: i64,
         reviews_needed: u16,
+        vesting: Option<VestingSchedule>,
     ) -> Result<()> {
         ctx.accounts.create_campaign(
             name,
@@ -35,6 +41,7 @@ pub mod realvue {
             start_time,
             end_time,
             reviews_needed,
+            vesting,
             &ctx.bumps,
         )?;
         Ok(())
@@ -50,22 +57,22 @@ pub mod realvue {
         Ok(())
     }
 
-    pub fn approve_review(
-        ctx: Context<ApproveReview>,
+    pub fn approve_review<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveReview<'info>>,
         approved: bool,
         flagged_reason: Option<String>,
     ) -> Result<()> {
-        ctx.accounts.approve_review(approved, flagged_reason)?;
+        ctx.accounts.approve_review(approved, flagged_reason, &ctx.bumps, ctx.remaining_accounts)?;
         Ok(())
     }
 
-    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
-        ctx.accounts.claim_reward()?;
+    pub fn claim_reward(ctx: Context<ClaimReward>, payout: RewardPayout) -> Result<()> {
+        ctx.accounts.claim_reward(payout)?;
         Ok(())
     }
 
-    pub fn claim_fee(ctx: Context<ClaimFee>) -> Result<()> {
-        ctx.accounts.claim_fee()?;
+    pub fn claim_fee(ctx: Context<ClaimFee>, amount: u64) -> Result<()> {
+        ctx.accounts.claim_fee(amount)?;
         Ok(())
     }
 
@@ -88,4 +95,201 @@ pub mod realvue {
         ctx.accounts.close_platform()?;
         Ok(())
     }
+
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+        ctx.accounts.set_fee_tiers(fee_tiers)?;
+        Ok(())
+    }
+
+    pub fn set_referral_share(ctx: Context<SetReferralShare>, referral_share_bps: u16) -> Result<()> {
+        ctx.accounts.set_referral_share(referral_share_bps)?;
+        Ok(())
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        ctx.accounts.register_referrer(&ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn claim_referral(ctx: Context<ClaimReferral>) -> Result<()> {
+        ctx.accounts.claim_referral()?;
+        Ok(())
+    }
+
+    pub fn set_withdrawal_timelock(ctx: Context<SetWithdrawalTimelock>, timelock: i64) -> Result<()> {
+        ctx.accounts.set_withdrawal_timelock(timelock)?;
+        Ok(())
+    }
+
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, amount: u64, destination: Pubkey) -> Result<()> {
+        ctx.accounts.request_withdrawal(amount, destination)?;
+        Ok(())
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        ctx.accounts.execute_withdrawal()?;
+        Ok(())
+    }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        ctx.accounts.cancel_withdrawal()?;
+        Ok(())
+    }
+
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        ctx.accounts.withdraw_vested()?;
+        Ok(())
+    }
+
+    pub fn clawback_reward(ctx: Context<ClawbackReward>, reason: String) -> Result<()> {
+        ctx.accounts.clawback_reward(reason)?;
+        Ok(())
+    }
+
+    pub fn set_review_bond(ctx: Context<SetReviewBond>, bond_amount: u64, bond_slash_target: BondSlashTarget) -> Result<()> {
+        ctx.accounts.set_review_bond(bond_amount, bond_slash_target)?;
+        Ok(())
+    }
+
+    pub fn set_trusted_platforms(ctx: Context<SetTrustedPlatforms>, trusted_platforms: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_trusted_platforms(trusted_platforms)?;
+        Ok(())
+    }
+
+    pub fn import_reputation(ctx: Context<ImportReputation>) -> Result<()> {
+        ctx.accounts.import_reputation()?;
+        Ok(())
+    }
+
+    pub fn migrate_platform(ctx: Context<MigratePlatform>) -> Result<()> {
+        ctx.accounts.migrate_platform()?;
+        Ok(())
+    }
+
+    pub fn migrate_campaign<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateCampaign<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.migrate_campaign(ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn migrate_review(ctx: Context<MigrateReview>) -> Result<()> {
+        ctx.accounts.migrate_review()?;
+        Ok(())
+    }
+
+    pub fn migrate_reviewer(ctx: Context<MigrateReviewer>) -> Result<()> {
+        ctx.accounts.migrate_reviewer()?;
+        Ok(())
+    }
+
+    pub fn set_attestors(ctx: Context<SetAttestors>, attestors: Vec<Pubkey>, attestation_threshold: u8) -> Result<()> {
+        ctx.accounts.set_attestors(attestors, attestation_threshold)?;
+        Ok(())
+    }
+
+    pub fn attest_review(ctx: Context<AttestReview>, attestor: Pubkey, verdict: bool, score: u8) -> Result<()> {
+        ctx.accounts.attest_review(attestor, verdict, score, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn sync_rank_badge(ctx: Context<SyncRankBadge>) -> Result<()> {
+        ctx.accounts.sync_rank_badge(&ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn set_cancellation_policy(ctx: Context<SetCancellationPolicy>, grace_period: i64, kill_fee_bps: u16) -> Result<()> {
+        ctx.accounts.set_cancellation_policy(grace_period, kill_fee_bps)?;
+        Ok(())
+    }
+
+    pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
+        ctx.accounts.cancel_campaign()?;
+        Ok(())
+    }
+
+    pub fn claim_kill_fee(ctx: Context<ClaimKillFee>) -> Result<()> {
+        ctx.accounts.claim_kill_fee()?;
+        Ok(())
+    }
+
+    pub fn enable_compression(ctx: Context<EnableCompression>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+        ctx.accounts.enable_compression(max_depth, max_buffer_size)?;
+        Ok(())
+    }
+
+    pub fn make_compressed_review(ctx: Context<MakeCompressedReview>, description: String, tx_id: String) -> Result<()> {
+        ctx.accounts.make_compressed_review(description, tx_id, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn approve_compressed_review<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveCompressedReview<'info>>,
+        review: CompressedReview,
+        root: [u8; 32],
+        approved: bool,
+        flagged_reason: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.approve_compressed_review(review, root, approved, flagged_reason, ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn claim_compressed_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimCompressedReward<'info>>,
+        review: CompressedReview,
+        root: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.claim_compressed_reward(review, root, ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn create_category(ctx: Context<CreateCategory>, name: String, policy: CategoryPolicy) -> Result<()> {
+        ctx.accounts.create_category(name, policy, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn update_category(ctx: Context<UpdateCategory>, policy: CategoryPolicy, active: bool) -> Result<()> {
+        ctx.accounts.update_category(policy, active)?;
+        Ok(())
+    }
+
+    pub fn reply_to_review(ctx: Context<ReplyToReview>, body: String) -> Result<()> {
+        ctx.accounts.reply_to_review(body, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn edit_reply(ctx: Context<EditReply>, body: String) -> Result<()> {
+        ctx.accounts.edit_reply(body)?;
+        Ok(())
+    }
+
+    pub fn close_reply(ctx: Context<CloseReply>) -> Result<()> {
+        ctx.accounts.close_reply()?;
+        Ok(())
+    }
+
+    pub fn moderate_reply(ctx: Context<ModerateReply>, hidden: bool) -> Result<()> {
+        ctx.accounts.moderate_reply(hidden)?;
+        Ok(())
+    }
+
+    pub fn set_review_sponsor(ctx: Context<SetReviewSponsor>, sponsor: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_review_sponsor(sponsor)?;
+        Ok(())
+    }
+
+    pub fn fund_rent_budget(ctx: Context<FundRentBudget>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_rent_budget(amount)?;
+        Ok(())
+    }
+
+    pub fn rename_campaign(ctx: Context<RenameCampaign>, name: String) -> Result<()> {
+        ctx.accounts.rename_campaign(name)?;
+        Ok(())
+    }
+
+    pub fn set_rev_payout(ctx: Context<SetRevPayout>, premium_bps: Option<u16>) -> Result<()> {
+        ctx.accounts.set_rev_payout(premium_bps)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,356 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}, Discriminator};
+
+use crate::{BondSlashTarget, PlatformConfig, ReviewAccount, ReviewCampaign, ReviewerAccount};
+
+// Layouts of the first deployment, from before accounts carried a version byte. Fields added
+// since sit between the old ones, so these are rebuilt field by field instead of zero-padded.
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PlatformConfigV0 {
+    pub seed: u64,
+    pub admin: Pubkey,
+    pub rev_mint: Pubkey,
+    pub platform_fee: u16,
+    pub is_active: bool,
+    pub total_campaigns: u64,
+    pub total_reviews: u64,
+    pub total_fees_collected: u64,
+    pub rev_bump: u8,
+    pub treasury_bump: u8,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ReviewCampaignV0 {
+    pub merchant: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(64)]
+    pub product_id: String,
+    pub deposit_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub active: bool,
+    pub reviews_needed: u16,
+    pub approved_count: u16,
+    pub refunded: bool,
+    pub vault_bump: u8,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ReviewAccountV0 {
+    pub campaign_id: Pubkey,
+    pub reviewer: Pubkey,
+    #[max_len(500)]
+    pub description: String,
+    pub approved: bool,
+    pub reward_claimed: bool,
+    #[max_len(64)]
+    pub flagged_reason: String,
+    #[max_len(88)]
+    pub tx_id: String,
+    pub reviewer_rank: u8,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+// Reviewer accounts lived at [b"reviewer", reviewer] before reputation was scoped per platform
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ReviewerAccountV0 {
+    pub reviewer: Pubkey,
+    pub approved_count: u16,
+    pub rank: u8,
+    pub total_earned: u64,
+    pub bump: u8,
+}
+
+impl ReviewerAccountV0 {
+    // The legacy seeds can't hold a current account, so anything else there is rejected
+    pub fn load(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() == 8 + Self::INIT_SPACE && data[..8] == *ReviewerAccount::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        Self::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+    }
+}
+
+// An unversioned layout and how its fields map onto the current struct, as version 0
+pub trait LegacyLayout<T>: AnchorDeserialize + Space {
+    fn upgrade(self) -> T;
+}
+
+impl LegacyLayout<PlatformConfig> for PlatformConfigV0 {
+    fn upgrade(self) -> PlatformConfig {
+        PlatformConfig {
+            version: 0,
+            seed: self.seed,
+            admin: self.admin,
+            rev_mint: self.rev_mint,
+            platform_fee: self.platform_fee,
+            fee_tiers: Vec::new(),
+            referral_share_bps: 0,
+            is_active: self.is_active,
+            total_campaigns: self.total_campaigns,
+            total_reviews: self.total_reviews,
+            // claim_fee used to add withdrawals here too, there is nothing to split them by
+            total_fees_collected: self.total_fees_collected,
+            total_fees_withdrawn: 0,
+            withdrawal_timelock: 0,
+            pending_withdrawal: None,
+            trusted_platforms: Vec::new(),
+            rev_bump: self.rev_bump,
+            treasury_bump: self.treasury_bump,
+            bump: self.bump,
+            attestors: Vec::new(),
+            attestation_threshold: 0,
+            cancellation_grace_period: 0,
+            kill_fee_bps: 0,
+            review_sponsor: None,
+            rev_payout_premium_bps: None,
+            pending_timelock: None,
+        }
+    }
+}
+
+impl LegacyLayout<ReviewCampaign> for ReviewCampaignV0 {
+    fn upgrade(self) -> ReviewCampaign {
+        ReviewCampaign {
+            version: 0,
+            merchant: self.merchant,
+            name: self.name,
+            product_id: self.product_id,
+            deposit_amount: self.deposit_amount,
+            fee_bps: 0, // the fee was read from the platform at claim time, migrate_campaign snapshots it
+            start_time: self.start_time,
+            end_time: self.end_time,
+            active: self.active,
+            reviews_needed: self.reviews_needed,
+            approved_count: self.approved_count,
+            refunded: self.refunded,
+            vesting: None,
+            vesting_locked: 0,
+            bond_amount: 0,
+            bond_slash_target: BondSlashTarget::Merchant,
+            vault_bump: self.vault_bump,
+            bump: self.bump,
+            pending_reviews: 0,
+            rewards_claimed: 0,
+            cancelled: false,
+            grace_ends_at: 0,
+            kill_fee: 0,
+            merkle_tree: None,
+            compressed_reviews: 0,
+            category: Pubkey::default(),
+            rent_budget: 0,
+            campaign_id: 0,
+            rev_paid_rewards: 0,
+            platform: Pubkey::default(),
+        }
+    }
+}
+
+impl LegacyLayout<ReviewAccount> for ReviewAccountV0 {
+    fn upgrade(self) -> ReviewAccount {
+        ReviewAccount {
+            version: 0,
+            campaign_id: self.campaign_id,
+            reviewer: self.reviewer,
+            description: self.description,
+            approved: self.approved,
+            reward_claimed: self.reward_claimed,
+            flagged_reason: self.flagged_reason,
+            tx_id: self.tx_id,
+            reviewer_rank: self.reviewer_rank,
+            timestamp: self.timestamp,
+            vesting_total: 0,
+            vesting_withdrawn: 0,
+            vesting_start: 0,
+            bond: 0,
+            bond_bump: 0,
+            bump: self.bump,
+            passing_attestations: 0,
+            failing_attestations: 0,
+        }
+    }
+}
+
+// Decodes an account written by any version of `T`. Unversioned accounts are told apart by
+// their size and rebuilt as version 0. Fields appended since a versioned layout read back as zero.
+pub fn load_any_version<T, L>(data: &[u8]) -> Result<T>
+where
+    T: AccountDeserialize + Discriminator + Space,
+    L: LegacyLayout<T>,
+{
+    require!(
+        data.len() >= 8 && data[..8] == *T::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    if data.len() == 8 + L::INIT_SPACE {
+        let legacy = L::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))?;
+
+        return Ok(legacy.upgrade());
+    }
+
+    let mut buf = data.to_vec();
+
+    if buf.len() < 8 + T::INIT_SPACE {
+        buf.resize(8 + T::INIT_SPACE, 0);
+    }
+
+    T::try_deserialize(&mut &buf[..])
+}
+
+// Grows a migrated account to `new_len`, the payer tops it up to the new rent-exempt minimum
+pub fn realloc_to<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_exempt: u64 = Rent::get()?.minimum_balance(new_len);
+    let top_up = rent_exempt.saturating_sub(account.lamports());
+
+    if top_up > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+
+        let ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+
+        transfer(ctx, top_up)?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Account data as the first deployment wrote it: discriminator, fields, zeroed tail
+    fn baseline_account<L: AnchorSerialize + Space>(discriminator: &[u8], legacy: &L) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(8 + L::INIT_SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn migrates_a_baseline_campaign() {
+        let merchant = Pubkey::new_unique();
+        let data = baseline_account(ReviewCampaign::DISCRIMINATOR, &ReviewCampaignV0 {
+            merchant,
+            name: "Drop".to_string(),
+            product_id: "P1".to_string(),
+            deposit_amount: 1_000_000_000,
+            start_time: 10,
+            end_time: 20,
+            active: true,
+            reviews_needed: 3,
+            approved_count: 2,
+            refunded: false,
+            vault_bump: 254,
+            bump: 253,
+        });
+
+        let campaign = load_any_version::<ReviewCampaign, ReviewCampaignV0>(&data).unwrap();
+        assert_eq!(campaign.version, 0);
+        assert_eq!(campaign.merchant, merchant);
+        assert_eq!(campaign.name, "Drop");
+        assert_eq!(campaign.deposit_amount, 1_000_000_000);
+        assert_eq!((campaign.start_time, campaign.end_time), (10, 20));
+        assert!(campaign.active && !campaign.refunded);
+        assert_eq!((campaign.reviews_needed, campaign.approved_count), (3, 2));
+        assert_eq!((campaign.vault_bump, campaign.bump), (254, 253));
+        assert!(campaign.vesting.is_none() && campaign.merkle_tree.is_none());
+        assert_eq!(campaign.campaign_id, 0);
+    }
+
+    #[test]
+    fn migrates_a_baseline_platform_and_review() {
+        let admin = Pubkey::new_unique();
+        let data = baseline_account(PlatformConfig::DISCRIMINATOR, &PlatformConfigV0 {
+            seed: 12,
+            admin,
+            rev_mint: Pubkey::new_unique(),
+            platform_fee: 500,
+            is_active: true,
+            total_campaigns: 4,
+            total_reviews: 9,
+            total_fees_collected: 77,
+            rev_bump: 1,
+            treasury_bump: 2,
+            bump: 3,
+        });
+
+        let platform = load_any_version::<PlatformConfig, PlatformConfigV0>(&data).unwrap();
+        assert_eq!((platform.version, platform.seed, platform.admin), (0, 12, admin));
+        assert_eq!(platform.platform_fee, 500);
+        assert!(platform.is_active && platform.fee_tiers.is_empty());
+        assert_eq!((platform.total_campaigns, platform.total_reviews, platform.total_fees_collected), (4, 9, 77));
+        assert_eq!((platform.rev_bump, platform.treasury_bump, platform.bump), (1, 2, 3));
+
+        let reviewer = Pubkey::new_unique();
+        let data = baseline_account(ReviewAccount::DISCRIMINATOR, &ReviewAccountV0 {
+            campaign_id: Pubkey::new_unique(),
+            reviewer,
+            description: "Great".to_string(),
+            approved: true,
+            reward_claimed: false,
+            flagged_reason: String::new(),
+            tx_id: "tx".to_string(),
+            reviewer_rank: 1,
+            timestamp: 42,
+            bump: 250,
+        });
+
+        let review = load_any_version::<ReviewAccount, ReviewAccountV0>(&data).unwrap();
+        assert_eq!((review.version, review.reviewer), (0, reviewer));
+        assert_eq!((review.description.as_str(), review.tx_id.as_str()), ("Great", "tx"));
+        assert!(review.approved && !review.reward_claimed);
+        assert_eq!((review.timestamp, review.bump, review.bond), (42, 250, 0));
+    }
+
+    #[test]
+    fn loads_a_baseline_reviewer_only() {
+        let reviewer = Pubkey::new_unique();
+        let data = baseline_account(ReviewerAccount::DISCRIMINATOR, &ReviewerAccountV0 {
+            reviewer,
+            approved_count: 7,
+            rank: 2,
+            total_earned: 300,
+            bump: 251,
+        });
+
+        let legacy = ReviewerAccountV0::load(&data).unwrap();
+        assert_eq!((legacy.reviewer, legacy.approved_count, legacy.rank), (reviewer, 7, 2));
+        assert_eq!((legacy.total_earned, legacy.bump), (300, 251));
+
+        let mut current = ReviewerAccount::DISCRIMINATOR.to_vec();
+        current.resize(8 + ReviewerAccount::INIT_SPACE, 0);
+        assert!(ReviewerAccountV0::load(&current).is_err());
+    }
+
+    #[test]
+    fn versioned_accounts_decode_as_a_prefix() {
+        let mut data = PlatformConfig::DISCRIMINATOR.to_vec();
+        data.resize(8 + PlatformConfig::INIT_SPACE - 4, 0);
+        data[8] = 5;
+
+        let platform = load_any_version::<PlatformConfig, PlatformConfigV0>(&data).unwrap();
+        assert_eq!(platform.version, 5);
+        assert!(platform.pending_timelock.is_none());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation{
+    pub review: Pubkey,
+    pub attestor: Pubkey,
+    pub verdict: bool, // true = authentic
+    pub score: u8, // fraud detection confidence, 0-100
+    pub timestamp: i64,
+    pub bump: u8,
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PlatformError;
+
+// Longest description a ReviewAccount can hold, categories can only tighten it
+pub const MAX_DESCRIPTION_LEN: u16 = 500;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct CategoryPolicy {
+    pub fee_bps: Option<u16>, // replaces the platform fee and volume tiers when set
+    pub min_deposit: u64,
+    pub min_reviewer_rank: u8,
+    pub max_description_len: u16,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Category{
+    pub platform: Pubkey,
+    #[max_len(32)]
+    pub name: String, // e.g. "electronics", "restaurants"
+    pub policy: CategoryPolicy,
+    pub active: bool, // inactive categories take no new campaigns
+    pub bump: u8,
+}
+
+impl Category {
+    // Minimum reviewer rank and description limit for a campaign's reviews.
+    // Campaigns migrated from before categories have none and keep the defaults.
+    pub fn review_limits(campaign_category: Pubkey, category: Option<&Account<Category>>) -> Result<(u8, u16)> {
+        match category {
+            Some(category) => {
+                require_keys_eq!(category.key(), campaign_category, PlatformError::InvalidCategory);
+                Ok((category.policy.min_reviewer_rank, category.policy.max_description_len))
+            }
+            None => {
+                require_keys_eq!(campaign_category, Pubkey::default(), PlatformError::InvalidCategory);
+                Ok((0, MAX_DESCRIPTION_LEN))
+            }
+        }
+    }
+}
+
+impl CategoryPolicy {
+    pub fn validate(&self) -> Result<()> {
+        // Same limit as init_platform (max 10% = 1000 basis points)
+        if let Some(fee_bps) = self.fee_bps {
+            require!(fee_bps <= 1000, PlatformError::InvalidFeePercentage);
+        }
+
+        require!(
+            self.min_deposit > 0
+                && self.min_reviewer_rank <= 5
+                && self.max_description_len > 0
+                && self.max_description_len <= MAX_DESCRIPTION_LEN,
+            PlatformError::InvalidCategoryPolicy
+        );
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+// Leaf schema for compressed campaigns, mirrors ReviewAccount. The tree only stores
+// the hash, the full review is emitted in CompressedReviewUpdated for indexers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedReview {
+    pub campaign_id: Pubkey,
+    pub reviewer: Pubkey,
+    pub description: String,
+    pub approved: bool,
+    pub reward_claimed: bool,
+    pub flagged_reason: String,
+    pub tx_id: String,
+    pub reviewer_rank: u8,
+    pub timestamp: i64,
+    pub leaf_index: u32, // position in the campaign's tree, also keeps identical reviews apart
+}
+
+impl CompressedReview {
+    pub fn leaf_hash(&self) -> Result<[u8; 32]> {
+        let data = self.try_to_vec()?;
+        Ok(keccak::hashv(&[&data]).to_bytes())
+    }
+
+    // Neither approved nor flagged, and not settled by a kill fee
+    pub fn is_pending(&self) -> bool {
+        !self.approved && self.flagged_reason.is_empty() && !self.reward_claimed
+    }
+}
+
+// One per (campaign, reviewer) in a compressed campaign, stands in for the ReviewAccount PDA
+// that limits uncompressed campaigns to a single review per reviewer
+#[account]
+#[derive(InitSpace)]
+pub struct CompressedReviewMarker {
+    pub campaign: Pubkey,
+    pub reviewer: Pubkey,
+    pub leaf_index: u32, // the reviewer's leaf in the campaign's tree
+    pub bump: u8,
+}
\ No newline at end of file
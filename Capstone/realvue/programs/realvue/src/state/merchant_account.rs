@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MerchantAccount{
+    pub merchant: Pubkey,
+    pub platform: Pubkey,
+    pub total_deposited: u64, // lifetime deposit volume, used for fee tiers
+    pub total_campaigns: u64,
+    pub bump: u8,
+}
\ No newline at end of file
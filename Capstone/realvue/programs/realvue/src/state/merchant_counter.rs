@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+// Hands out a merchant's campaign ids, shared across platforms since campaign PDAs are
+#[account]
+#[derive(InitSpace)]
+pub struct MerchantCounter{
+    pub merchant: Pubkey,
+    pub campaign_count: u64, // ids handed out so far, the last campaign's id
+    pub bump: u8,
+}
+
+impl MerchantCounter {
+    // Ids start at 1, campaign_id 0 marks campaigns created before ids
+    pub fn next_campaign_id(&self) -> u64 {
+        self.campaign_count.saturating_add(1)
+    }
+}
\ No newline at end of file
@@ -2,8 +2,24 @@ pub mod platform_config;
 pub mod review_campaign;
 pub mod reviewer_account;
 pub mod review_account;
+pub mod merchant_account;
+pub mod referrer_account;
+pub mod attestation;
+pub mod compressed_review;
+pub mod category;
+pub mod purchase_record;
+pub mod review_reply;
+pub mod merchant_counter;
 
 pub use platform_config::*;
 pub use review_campaign::*;
 pub use reviewer_account::*;
-pub use review_account::*;
\ No newline at end of file
+pub use review_account::*;
+pub use merchant_account::*;
+pub use referrer_account::*;
+pub use attestation::*;
+pub use compressed_review::*;
+pub use category::*;
+pub use purchase_record::*;
+pub use review_reply::*;
+pub use merchant_counter::*;
\ No newline at end of file
@@ -1,17 +1,124 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::PlatformError;
+
+// Bump when the layout changes, new fields are appended at the end
+pub const PLATFORM_CONFIG_VERSION: u8 = 6;
+
+pub const MAX_FEE_TIERS: usize = 5;
+pub const MAX_TRUSTED_PLATFORMS: usize = 5;
+pub const MAX_ATTESTORS: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct FeeTier {
+    pub min_volume: u64, // lifetime merchant deposit (lamports) needed to reach this tier
+    pub fee_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PendingWithdrawal {
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub executable_at: i64, // earliest time execute_withdrawal can run
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PendingTimelock {
+    pub timelock: i64, // shorter delay waiting for the current one to pass
+    pub effective_at: i64, // earliest time the shorter delay applies
+}
+
+// Currency a reviewer takes their reward in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RewardPayout {
+    Sol,
+    Rev, // minted at the platform's premium, the SOL stays in the vault for the merchant
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformConfig{
+    pub version: u8,
     pub seed: u64,
     pub admin: Pubkey, //admin of the platform
     pub rev_mint: Pubkey, // rev token mint
-    pub platform_fee: u16,
+    pub platform_fee: u16, // base fee, charged when no tier applies
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<FeeTier>, // sorted by min_volume, ascending
+    pub referral_share_bps: u16, // share of each campaign fee credited to the referrer
     pub is_active: bool,
     pub total_campaigns: u64,
     pub total_reviews: u64,
-    pub total_fees_collected: u64,
+    pub total_fees_collected: u64, // fees that reached the treasury
+    pub total_fees_withdrawn: u64, // fees moved out of the treasury
+    pub withdrawal_timelock: i64, // seconds between request and execution, 0 = withdraw directly
+    pub pending_withdrawal: Option<PendingWithdrawal>,
+    #[max_len(MAX_TRUSTED_PLATFORMS)]
+    pub trusted_platforms: Vec<Pubkey>, // platforms whose reviewer ranks can be imported
     pub rev_bump: u8,
     pub treasury_bump: u8,
     pub bump: u8,
+    // v2
+    #[max_len(MAX_ATTESTORS)]
+    pub attestors: Vec<Pubkey>, // off-chain fraud detection keys allowed to attest reviews
+    pub attestation_threshold: u8, // passing attestations approve_review needs, 0 = not required
+    // v3
+    pub cancellation_grace_period: i64, // seconds moderators get to resolve pending reviews of a cancelled campaign
+    pub kill_fee_bps: u16, // share of the per-review reward paid to reviews still pending after the grace period
+    // v4
+    pub review_sponsor: Option<Pubkey>, // fee payer allowed to front rent for reviewers
+    // v5
+    pub rev_payout_premium_bps: Option<u16>, // extra REV on top of 1 REV unit per lamport, None = SOL payouts only
+    // v6
+    pub pending_timelock: Option<PendingTimelock>, // queued decrease of withdrawal_timelock
+}
+
+impl PlatformConfig {
+    // Fee in basis points for a merchant with the given lifetime deposit volume
+    pub fn fee_for_volume(&self, volume: u64) -> u16 {
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|tier| volume >= tier.min_volume)
+            .map(|tier| tier.fee_bps)
+            .unwrap_or(self.platform_fee)
+    }
+
+    // REV minted for a reward of `lamports`, REV is issued 1:1 with lamports plus the premium
+    pub fn rev_for_reward(&self, lamports: u64) -> Result<u64> {
+        let premium_bps = self.rev_payout_premium_bps
+            .ok_or(PlatformError::RevPayoutUnavailable)?;
+
+        let rev_amount = (lamports as u128)
+            .checked_mul(10000 + premium_bps as u128)
+            .ok_or(PlatformError::RewardCalculationError)?
+            .checked_div(10000)
+            .ok_or(PlatformError::RewardCalculationError)?;
+
+        u64::try_from(rev_amount).map_err(|_| error!(PlatformError::RewardCalculationError))
+    }
+
+    // A queued timelock decrease takes effect once the old delay has passed
+    pub fn apply_pending_timelock(&mut self, now: i64) {
+        if let Some(pending) = self.pending_timelock {
+            if now >= pending.effective_at {
+                self.withdrawal_timelock = pending.timelock;
+                self.pending_timelock = None;
+            }
+        }
+    }
+
+    // Whether `payer` is fronting rent for `reviewer`, only the platform's sponsor may
+    pub fn is_sponsored(&self, payer: Pubkey, reviewer: Pubkey) -> Result<bool> {
+        if payer == reviewer {
+            return Ok(false);
+        }
+
+        require!(
+            self.review_sponsor == Some(payer),
+            PlatformError::UnauthorizedSponsor
+        );
+
+        Ok(true)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+// Claims a purchase proof for one review, seeded by purchase_hash(campaign, tx_id)
+#[account]
+#[derive(InitSpace)]
+pub struct PurchaseRecord{
+    pub campaign: Pubkey,
+    pub review: Pubkey, // review the tx_id backs
+    pub reviewer: Pubkey,
+    pub bump: u8,
+}
+
+impl PurchaseRecord {
+    // tx_id can be up to 88 bytes, past the 32 byte seed limit, so the seed is its hash
+    pub fn purchase_hash(campaign: &Pubkey, tx_id: &str) -> [u8; 32] {
+        hashv(&[campaign.as_ref(), tx_id.as_bytes()]).to_bytes()
+    }
+}
\ No newline at end of file
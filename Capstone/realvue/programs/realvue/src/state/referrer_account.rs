@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerAccount{
+    pub referrer: Pubkey,
+    pub platform: Pubkey,
+    pub pending_rewards: u64, // lamports held by this PDA, claimable by the referrer
+    pub total_earned: u64,
+    pub total_campaigns: u64, // campaigns created with this referrer
+    pub bump: u8,
+}
\ No newline at end of file
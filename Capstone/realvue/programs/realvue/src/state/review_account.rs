@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 
+// Bump when the layout changes, new fields are appended at the end
+pub const REVIEW_ACCOUNT_VERSION: u8 = 2;
+
 #[account]
 #[derive(InitSpace)]
 pub struct ReviewAccount{
+    pub version: u8,
     pub campaign_id: Pubkey,
     pub reviewer: Pubkey,
     #[max_len(500)]
@@ -15,5 +19,20 @@ pub struct ReviewAccount{
     pub tx_id: String, 
     pub reviewer_rank: u8,
     pub timestamp: i64,
+    pub vesting_total: u64, // reward allocated to this review when the campaign vests
+    pub vesting_withdrawn: u64,
+    pub vesting_start: i64,
+    pub bond: u64, // bond posted with the review, held in the bond vault until moderation
+    pub bond_bump: u8,
     pub bump: u8,
+    // v2
+    pub passing_attestations: u8, // raw tally, approve_review recounts against the platform's attestors
+    pub failing_attestations: u8,
+}
+
+impl ReviewAccount {
+    // A clawed-back review keeps its claim but loses its approval, what is left of vesting_total is fully vested
+    pub fn is_clawed_back(&self) -> bool {
+        self.reward_claimed && !self.approved
+    }
 }
\ No newline at end of file
@@ -1,20 +1,179 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::PlatformError;
+
+pub const MAX_CAMPAIGN_NAME_LEN: usize = 32;
+
+// Bump when the layout changes, new fields are appended at the end
+pub const REVIEW_CAMPAIGN_VERSION: u8 = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct VestingSchedule {
+    pub cliff: i64, // seconds after the claim before anything vests
+    pub duration: i64, // seconds of linear release after the cliff
+    pub clawback_window: i64, // seconds after the claim during which the reward can be clawed back
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BondSlashTarget {
+    Merchant,
+    Treasury,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct ReviewCampaign{
+    pub version: u8,
     pub merchant: Pubkey, // owner of the campaign
-    #[max_len(32)]
-    pub name: String, // name of the campaign
+    #[max_len(MAX_CAMPAIGN_NAME_LEN)]
+    pub name: String, // display name, only part of the address for campaigns without an id
     #[max_len(64)]
     pub product_id: String,
     pub deposit_amount: u64,
+    pub fee_bps: u16, // platform fee charged at creation, reward and refund math uses this
     pub start_time: i64,
     pub end_time: i64,
     pub active: bool,
     pub reviews_needed: u16,
     pub approved_count: u16,
     pub refunded: bool,
+    pub vesting: Option<VestingSchedule>, // rewards vest instead of paying out at once
+    pub vesting_locked: u64, // vesting rewards still held in the vault for reviewers
+    pub bond_amount: u64, // bond a Bronze reviewer posts per review, 0 = no bond
+    pub bond_slash_target: BondSlashTarget, // where bonds of flagged reviews go
     pub vault_bump: u8,
-    pub bump: u8, 
+    pub bump: u8,
+    // v2
+    pub pending_reviews: u16, // submitted reviews not yet approved or flagged
+    pub rewards_claimed: u16, // approved reviews whose reward has been claimed
+    pub cancelled: bool,
+    pub grace_ends_at: i64, // end of the moderation grace period after cancellation
+    pub kill_fee: u64, // paid to each review still pending when the grace period ends
+    // v3
+    pub merkle_tree: Option<Pubkey>, // reviews are leaves of this tree instead of ReviewAccount PDAs
+    pub compressed_reviews: u32, // leaves appended so far, the next leaf index
+    // v4
+    pub category: Pubkey, // default for campaigns migrated from before categories
+    // v5
+    pub rent_budget: u64, // vault lamports on top of the deposit that reimburse sponsored review rent
+    // v6
+    pub campaign_id: u64, // per-merchant id from MerchantCounter, 0 for campaigns keyed by name
+    // v7
+    pub rev_paid_rewards: u64, // SOL rewards reviewers took as REV, refundable to the merchant
+    // v8
+    pub platform: Pubkey, // platform the campaign was created under, its policies and sponsor apply
+}
+
+impl ReviewCampaign {
+    // PDA seeds after b"campaign": [merchant, campaign_id] for id-keyed campaigns. Campaigns
+    // created before ids stay at their [name, merchant] address and can't be renamed.
+    pub fn address_seeds(&self) -> [Vec<u8>; 2] {
+        match self.campaign_id {
+            0 => [self.name.as_bytes().to_vec(), self.merchant.to_bytes().to_vec()],
+            id => [self.merchant.to_bytes().to_vec(), id.to_le_bytes().to_vec()],
+        }
+    }
+
+    pub fn validate_name(name: &str) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_CAMPAIGN_NAME_LEN,
+            PlatformError::InvalidCampaignName
+        );
+
+        Ok(())
+    }
+
+    // Platform fee taken from the deposit (basis points)
+    pub fn fee_amount(&self) -> Result<u64> {
+        let fee_amount = (self.deposit_amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .ok_or(PlatformError::FeeCalculationError)?
+            .checked_div(10000)
+            .ok_or(PlatformError::FeeCalculationError)? as u64;
+
+        Ok(fee_amount)
+    }
+
+    // Reward of one approved review, the deposit minus fee split over the reviews needed
+    pub fn reward_per_reviewer(&self) -> Result<u64> {
+        let reward_pool = self.deposit_amount
+            .checked_sub(self.fee_amount()?)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let reward_per_reviewer = reward_pool
+            .checked_div(self.reviews_needed as u64)
+            .ok_or(PlatformError::RewardCalculationError)?;
+
+        Ok(reward_per_reviewer)
+    }
+
+    // Vault balance a cancelled campaign still owes: unclaimed rewards plus kill fees
+    pub fn reserved_for_reviewers(&self) -> Result<u64> {
+        let unclaimed = self.approved_count.saturating_sub(self.rewards_claimed) as u64;
+
+        let rewards = unclaimed
+            .checked_mul(self.reward_per_reviewer()?)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let kill_fees = (self.pending_reviews as u64)
+            .checked_mul(self.kill_fee)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        let reserved = rewards
+            .checked_add(kill_fees)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        Ok(reserved)
+    }
+
+    // Bond for a reviewer of the given rank, higher ranks get a discount
+    pub fn bond_for_rank(&self, rank: u8) -> Result<u64> {
+        let discount_percent: u64 = match rank {
+            0..=1 => 0,  // Bronze
+            2 => 10,     // Silver
+            3 => 25,     // Gold
+            4 => 50,     // Platinum
+            _ => 75,     // Diamond
+        };
+
+        let bond = (self.bond_amount as u128)
+            .checked_mul((100 - discount_percent) as u128)
+            .ok_or(PlatformError::ArithmeticOverflow)?
+            .checked_div(100)
+            .ok_or(PlatformError::ArithmeticOverflow)? as u64;
+
+        Ok(bond)
+    }
+
+    // Vault balance the merchant can take back, vesting rewards stay for reviewers
+    pub fn refundable_balance(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.vesting_locked)
+    }
+}
+
+impl VestingSchedule {
+    // Portion of `total` vested at `now` for a schedule that started at `start`
+    pub fn vested_amount(&self, total: u64, start: i64, now: i64) -> Result<u64> {
+        let cliff_end = start
+            .checked_add(self.cliff)
+            .ok_or(PlatformError::ArithmeticOverflow)?;
+
+        if now < cliff_end {
+            return Ok(0);
+        }
+
+        let elapsed = now - cliff_end;
+
+        if self.duration == 0 || elapsed >= self.duration {
+            return Ok(total);
+        }
+
+        let vested = (total as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(PlatformError::RewardCalculationError)?
+            .checked_div(self.duration as u128)
+            .ok_or(PlatformError::RewardCalculationError)? as u64;
+
+        Ok(vested)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PlatformError;
+
+pub const MAX_REPLY_LEN: usize = 280;
+
+// A merchant's public response to a review, one per review
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewReply{
+    pub platform: Pubkey,
+    pub campaign: Pubkey,
+    pub review: Pubkey,
+    pub merchant: Pubkey,
+    #[max_len(280)]
+    pub body: String,
+    pub hidden: bool, // set by the platform admin, clients shouldn't show hidden replies
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ReviewReply {
+    pub fn validate_body(body: &str) -> Result<()> {
+        require!(
+            !body.is_empty() && body.len() <= MAX_REPLY_LEN,
+            PlatformError::InvalidReplyLength
+        );
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -4,8 +4,34 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct ReviewerAccount{
     pub reviewer: Pubkey,
+    pub platform: Pubkey, // reputation is scoped to this platform
     pub approved_count: u16,
     pub rank: u8,
     pub total_earned: u64,
+    pub badge_rank: u8, // rank shown on the soulbound badge, 0 = no badge yet
+    pub rent_debt: u64, // sponsored rent not yet repaid, deducted from the next reward
     pub bump: u8
+}
+
+impl ReviewerAccount {
+    // Rank tier for a number of approved reviews
+    pub fn rank_for(approved_count: u16) -> u8 {
+        match approved_count {
+            0..=4 => 1,      // Bronze
+            5..=14 => 2,     // Silver
+            15..=49 => 3,    // Gold
+            50..=99 => 4,    // Platinum
+            _ => 5,          // Diamond
+        }
+    }
+
+    pub fn rank_name(rank: u8) -> &'static str {
+        match rank {
+            0..=1 => "Bronze",
+            2 => "Silver",
+            3 => "Gold",
+            4 => "Platinum",
+            _ => "Diamond",
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,391 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Realvue } from "../target/types/realvue";
+import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import { keccak_256 } from "@noble/hashes/sha3";
+import { sha256 } from "@noble/hashes/sha256";
+
+const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID = new PublicKey("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const SPL_NOOP_PROGRAM_ID = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+// Concurrent merkle tree account layout: header, sequence number, active index and buffer size,
+// then the changelog ring buffer and the rightmost proof. Every node is 32 bytes.
+const TREE_HEADER_SIZE = 56;
+const changelogSize = (maxDepth: number) => 32 + 32 * maxDepth + 8;
+const treeAccountSize = (maxDepth: number, maxBufferSize: number) =>
+  TREE_HEADER_SIZE + 24 + maxBufferSize * changelogSize(maxDepth) + (32 * maxDepth + 32 + 8);
+
+// The root the tree account currently holds, from the changelog entry at the active index
+const currentRoot = (data: Buffer, maxDepth: number) => {
+  const activeIndex = Number(data.readBigUInt64LE(TREE_HEADER_SIZE + 8));
+  const offset = TREE_HEADER_SIZE + 24 + activeIndex * changelogSize(maxDepth);
+  return data.subarray(offset, offset + 32);
+};
+
+// Root and proofs of a tree holding `leaves` from index 0, empty leaves are zeroes and pairs
+// are hashed with keccak like the compression program does
+const sparseMerkleTree = (leaves: Buffer[], maxDepth: number) => {
+  const hashPair = (left: Buffer, right: Buffer) => Buffer.from(keccak_256(Buffer.concat([left, right])));
+
+  const empty = [Buffer.alloc(32)];
+  const levels = [leaves];
+  for (let depth = 0; depth < maxDepth; depth++) {
+    empty.push(hashPair(empty[depth], empty[depth]));
+
+    const nodes = levels[depth];
+    const parents: Buffer[] = [];
+    for (let i = 0; i < nodes.length; i += 2) {
+      parents.push(hashPair(nodes[i], nodes[i + 1] ?? empty[depth]));
+    }
+    levels.push(parents);
+  }
+
+  return {
+    root: levels[maxDepth][0] ?? empty[maxDepth],
+    getProof: (index: number) =>
+      levels.slice(0, maxDepth).map((nodes, depth) => nodes[(index >> depth) ^ 1] ?? empty[depth]),
+  };
+};
+
+// Compressed campaigns need account-compression and noop cloned from mainnet (see Anchor.toml),
+// so they run on their own platform and wallets instead of inside the main suite
+describe("realvue compressed reviews", () => {
+  const provider = anchor.AnchorProvider.env()
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.realvue as Program<Realvue>;
+
+  const connection = provider.connection;
+
+  const seed = new BN(13);
+  const admin = Keypair.generate();
+  const merchant = Keypair.generate();
+  const reviewer = Keypair.generate();
+  const reviewer2 = Keypair.generate();
+
+  const PLATFORM_FEE = 500;
+  const CATEGORY_NAME = "compressed";
+  const PRODUCT_ID = "PRODUCT123";
+  const DEPOSIT_AMOUNT = new BN(0.5 * LAMPORTS_PER_SOL);
+  const REVIEWS_NEEDED = 3;
+  const MAX_DEPTH = 14;
+  const MAX_BUFFER_SIZE = 64;
+  const START_TIME = new BN(Math.floor(Date.now() / 1000));
+  const END_TIME = new BN(Math.floor(Date.now() / 1000) + 3600 * 24 * 7);
+  const REVIEW_DESCRIPTION = "Really impressed with the creativity behind this NFT drop.";
+  const TX_ID = "5j7s8K2FxVqp9Rm3nL4wY6tE1qW8rT5uI9oP3aS7dF2gH1kJ6mN8xC4vB5zA9yX2qE3rT6yU8iO1pA4sD7fG9hJ2";
+  const TX_ID2 = "2d4f6H8jK1lP3nM5qR7tY9uI1oE3wQ5aS7dF9gH2jK4lN6pR8tY1uI3oE5wQ7aS9dF2gH4jK6lN8pR1tY3uI5oE7w";
+
+  const platform = PublicKey.findProgramAddressSync(
+    [Buffer.from("realvue"), seed.toBuffer("le", 8), admin.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  const treasury = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), platform.toBuffer()],
+    program.programId
+  )[0];
+
+  const revMint = PublicKey.findProgramAddressSync(
+    [Buffer.from("rev"), platform.toBuffer()],
+    program.programId
+  )[0];
+
+  const category = PublicKey.findProgramAddressSync(
+    [Buffer.from("category"), platform.toBuffer(), Buffer.from(CATEGORY_NAME)],
+    program.programId
+  )[0];
+
+  const merchantCounter = PublicKey.findProgramAddressSync(
+    [Buffer.from("merchant_counter"), merchant.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  const merchantAccount = PublicKey.findProgramAddressSync(
+    [Buffer.from("merchant"), platform.toBuffer(), merchant.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  const campaign = PublicKey.findProgramAddressSync(
+    [Buffer.from("campaign"), merchant.publicKey.toBuffer(), new BN(1).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+
+  const vault = PublicKey.findProgramAddressSync(
+    [Buffer.from("campaign_vault"), campaign.toBuffer()],
+    program.programId
+  )[0];
+
+  const reviewerAccount = PublicKey.findProgramAddressSync(
+    [Buffer.from("reviewer"), platform.toBuffer(), reviewer.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  const reviewerAccountFor = (wallet: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("reviewer"), platform.toBuffer(), wallet.toBuffer()],
+    program.programId
+  )[0];
+
+  const purchaseRecordFor = (txId: string) => PublicKey.findProgramAddressSync(
+    [Buffer.from("purchase"), Buffer.from(sha256(Buffer.concat([campaign.toBuffer(), Buffer.from(txId)])))],
+    program.programId
+  )[0];
+
+  const purchaseRecord = purchaseRecordFor(TX_ID);
+
+  // One per reviewer and campaign, like the ReviewAccount PDA of uncompressed campaigns
+  const reviewMarkerFor = (wallet: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("compressed_review"), campaign.toBuffer(), wallet.toBuffer()],
+    program.programId
+  )[0];
+
+  const merkleTree = Keypair.generate();
+
+  const compressionAccounts = {
+    campaign: campaign,
+    platform: platform,
+    merkleTree: merkleTree.publicKey,
+    compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    noopProgram: SPL_NOOP_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+  };
+
+  // The leaf contents only live in the event, read them back like an indexer would
+  const readReview = async (signature: string) => {
+    const tx = await connection.getTransaction(signature, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    for (const event of parser.parseLogs(tx.meta.logMessages)) {
+      if (event.name === "compressedReviewUpdated") {
+        return event.data.review as any;
+      }
+    }
+    throw new Error("CompressedReviewUpdated not emitted");
+  };
+
+  const leafHash = (review: any) =>
+    Buffer.from(keccak_256(program.coder.types.encode("compressedReview", review)));
+
+  const proofFor = async (review: any) => {
+    const tree = sparseMerkleTree([leafHash(review)], MAX_DEPTH);
+    const treeAccount = await connection.getAccountInfo(merkleTree.publicKey, "confirmed");
+    expect(currentRoot(treeAccount.data, MAX_DEPTH).equals(tree.root)).to.be.true;
+
+    return {
+      root: Array.from(tree.root),
+      proof: tree.getProof(0).map((node) => ({ pubkey: new PublicKey(node), isSigner: false, isWritable: false })),
+    };
+  };
+
+  before(async () => {
+    const fund = new anchor.web3.Transaction().add(
+      SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: admin.publicKey, lamports: 1 * LAMPORTS_PER_SOL }),
+      SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: merchant.publicKey, lamports: 2 * LAMPORTS_PER_SOL }),
+      SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: reviewer.publicKey, lamports: 0.1 * LAMPORTS_PER_SOL }),
+      SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: reviewer2.publicKey, lamports: 0.1 * LAMPORTS_PER_SOL }),
+    );
+    await provider.sendAndConfirm(fund);
+
+    await program.methods.initPlatform(seed, PLATFORM_FEE)
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        revMint: revMint,
+        treasury: treasury,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createCategory(CATEGORY_NAME, {
+        feeBps: null,
+        minDeposit: new BN(0.1 * LAMPORTS_PER_SOL),
+        minReviewerRank: 0,
+        maxDescriptionLen: 500,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        category: category,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const merchantAta = (await getOrCreateAssociatedTokenAccount(connection, merchant, revMint, merchant.publicKey, true)).address;
+
+    await program.methods
+      .createCampaign("Compressed Campaign", PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
+        campaign: campaign,
+        merchantAccount: merchantAccount,
+        merchantAta: merchantAta,
+        platform: platform,
+        revMint: revMint,
+        vault: vault,
+        treasury: treasury,
+        category: category,
+        referrerAccount: null,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    for (const wallet of [reviewer, reviewer2]) {
+      await program.methods.initReviewer()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          reviewer: wallet.publicKey,
+          reviewerAccount: reviewerAccountFor(wallet.publicKey),
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+    }
+  });
+
+  it("Should enable compression on a campaign without reviews", async () => {
+    // The tree account is allocated by the client, the program initializes it with the campaign as authority
+    const space = treeAccountSize(MAX_DEPTH, MAX_BUFFER_SIZE);
+    const allocIx = SystemProgram.createAccount({
+      fromPubkey: merchant.publicKey,
+      newAccountPubkey: merkleTree.publicKey,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      space,
+      programId: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    });
+
+    const enableIx = await program.methods
+      .enableCompression(MAX_DEPTH, MAX_BUFFER_SIZE)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        ...compressionAccounts,
+      })
+      .instruction();
+
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(allocIx, enableIx),
+      [merchant, merkleTree]
+    );
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.merkleTree.toBase58()).to.equal(merkleTree.publicKey.toBase58());
+  });
+
+  it("Should append, approve and pay a review stored as a merkle leaf", async () => {
+    const appendTx = await program.methods
+      .makeCompressedReview(REVIEW_DESCRIPTION, TX_ID)
+      .accountsPartial({
+        reviewer: reviewer.publicKey,
+        reviewerAccount: reviewerAccount,
+        category: category,
+        reviewMarker: reviewMarkerFor(reviewer.publicKey),
+        purchaseRecord: purchaseRecord,
+        ...compressionAccounts,
+      })
+      .signers([reviewer])
+      .rpc({ commitment: "confirmed" });
+
+    const review = await readReview(appendTx);
+    expect(review.leafIndex).to.equal(0);
+
+    const record = await program.account.purchaseRecord.fetch(purchaseRecord);
+    expect(record.reviewer.toBase58()).to.equal(reviewer.publicKey.toBase58());
+
+    const marker = await program.account.compressedReviewMarker.fetch(reviewMarkerFor(reviewer.publicKey));
+    expect(marker.leafIndex).to.equal(0);
+
+    let { root, proof } = await proofFor(review);
+    const approveTx = await program.methods
+      .approveCompressedReview(review, root, true, "")
+      .accountsPartial({
+        admin: admin.publicKey,
+        reviewerAccount: reviewerAccount,
+        ...compressionAccounts,
+      })
+      .remainingAccounts(proof)
+      .signers([admin])
+      .rpc({ commitment: "confirmed" });
+
+    const approved = await readReview(approveTx);
+    expect(approved.approved).to.be.true;
+
+    ({ root, proof } = await proofFor(approved));
+    const balanceBefore = await connection.getBalance(reviewer.publicKey);
+
+    await program.methods
+      .claimCompressedReward(approved, root)
+      .accountsPartial({
+        reviewer: reviewer.publicKey,
+        vault: vault,
+        reviewerAccount: reviewerAccount,
+        ...compressionAccounts,
+      })
+      .remainingAccounts(proof)
+      .signers([reviewer])
+      .rpc({ commitment: "confirmed" });
+
+    expect(await connection.getBalance(reviewer.publicKey)).to.be.greaterThan(balanceBefore);
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.compressedReviews).to.equal(1);
+    expect(campaignAccount.rewardsClaimed).to.equal(1);
+  });
+
+  it("Should fail when a purchase transaction backs a second compressed review", async () => {
+    try {
+      await program.methods
+        .makeCompressedReview(REVIEW_DESCRIPTION, TX_ID)
+        .accountsPartial({
+          reviewer: reviewer2.publicKey,
+          reviewerAccount: reviewerAccountFor(reviewer2.publicKey),
+          category: category,
+          reviewMarker: reviewMarkerFor(reviewer2.publicKey),
+          purchaseRecord: purchaseRecord,
+          ...compressionAccounts,
+        })
+        .signers([reviewer2])
+        .rpc();
+
+      expect.fail("Should have failed - the purchase already backs a review");
+    } catch (error) {
+      expect(error.message).to.include("DuplicatePurchaseProof");
+    }
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.compressedReviews).to.equal(1);
+  });
+
+  it("Should fail when a reviewer appends a second review with another purchase", async () => {
+    try {
+      await program.methods
+        .makeCompressedReview(REVIEW_DESCRIPTION, TX_ID2)
+        .accountsPartial({
+          reviewer: reviewer.publicKey,
+          reviewerAccount: reviewerAccount,
+          category: category,
+          reviewMarker: reviewMarkerFor(reviewer.publicKey),
+          purchaseRecord: purchaseRecordFor(TX_ID2),
+          ...compressionAccounts,
+        })
+        .signers([reviewer])
+        .rpc();
+
+      expect.fail("Should have failed - the reviewer already reviewed this campaign");
+    } catch (error) {
+      expect(error.message).to.include("already in use");
+    }
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.compressedReviews).to.equal(1);
+  });
+});
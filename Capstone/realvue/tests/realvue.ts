@@ -2,8 +2,8 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Realvue } from "../target/types/realvue";
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL, Commitment } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL, Commitment, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount, getAssociatedTokenAddressSync, getAccount } from "@solana/spl-token";
 import { expect } from "chai";
 import adminKey from './wallets/admin.json';
 import merchantKey from './wallets/wallet.json';
@@ -11,6 +11,7 @@ import reviewer1Key from './wallets/wallet1.json';
 import reviewer2Key from './wallets/wallet2.json';
 import reviewer3Key from './wallets/wallet3.json';
 import { BN } from "bn.js";
+import { sha256 } from "@noble/hashes/sha256";
 
 const commitment: Commitment = 'confirmed';
 
@@ -34,12 +35,18 @@ describe("realvue", () => {
 
   let merchantAta: PublicKey;
 
+  // Off-chain fraud detection key, only ever signs messages
+  const attestor = Keypair.generate();
+
   const PLATFORM_FEE = 500; // 5% in basis points
+  const REV_PREMIUM_BPS = 1000; // reviewers taking REV get 10% more than the SOL reward
   const CAMPAIGN_NAME = "Share Thoughts on My Crypto Art";
   const PRODUCT_ID = "PRODUCT123";
+  const CATEGORY_NAME = "digital-art";
 
   const DEPOSIT_AMOUNT = new anchor.BN(1 * LAMPORTS_PER_SOL); // 1 SOL
   const REVIEWS_NEEDED = 3;
+  const REVIEW_BOND = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
   const REVIEW_DESCRIPTION1 = "Really impressed with the creativity behind this NFT drop. The designs are bold, but the marketplace UI could be a bit more user-friendly. Overall, a solid addition to my digital wallet!";
   const REVIEW_DESCRIPTION2 = "I’ve been exploring this NFT project for a couple of weeks, and it’s exceeded my expectations. The community engagement is top-notch. Highly recommend!";
   const REVIEW_DESCRIPTION3 = "The art in this NFT series is fantastic, with a lot of attention to detail. However, the transaction fees were a bit high during the mint. Still, I’m happy with my purchase and excited for future drops.";
@@ -92,8 +99,37 @@ describe("realvue", () => {
     program.programId)[0];
 
 
-  let campaign = PublicKey.findProgramAddressSync(
-    [Buffer.from("campaign"), Buffer.from(CAMPAIGN_NAME), merchant.publicKey.toBuffer()],
+  let category = PublicKey.findProgramAddressSync(
+    [Buffer.from("category"), platform.toBuffer(), Buffer.from(CATEGORY_NAME)],
+    program.programId
+  )[0];
+
+  // Campaigns are keyed by the merchant's campaign counter, names are plain metadata
+  let merchantCounter = PublicKey.findProgramAddressSync(
+    [Buffer.from("merchant_counter"), merchant.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  const campaignFor = (campaignId: number) => PublicKey.findProgramAddressSync(
+    [Buffer.from("campaign"), merchant.publicKey.toBuffer(), new BN(campaignId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+
+  const nextCampaign = async () => {
+    const counter = await program.account.merchantCounter.fetch(merchantCounter);
+    return campaignFor(counter.campaignCount.toNumber() + 1);
+  };
+
+  let campaign = campaignFor(1);
+
+  let merchantAccount = PublicKey.findProgramAddressSync(
+    [Buffer.from("merchant"), platform.toBuffer(), merchant.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  // reviewer3 doubles as the agency that referred the merchant
+  let referrerAccount = PublicKey.findProgramAddressSync(
+    [Buffer.from("referrer"), platform.toBuffer(), reviewer3.publicKey.toBuffer()],
     program.programId
   )[0];
 
@@ -104,17 +140,17 @@ describe("realvue", () => {
 
   // reviewer accounts
   let reviewer1Account = PublicKey.findProgramAddressSync(
-    [Buffer.from("reviewer"), reviewer1.publicKey.toBuffer()],
+    [Buffer.from("reviewer"), platform.toBuffer(), reviewer1.publicKey.toBuffer()],
     program.programId
   )[0];
 
   let reviewer2Account = PublicKey.findProgramAddressSync(
-    [Buffer.from("reviewer"), reviewer2.publicKey.toBuffer()],
+    [Buffer.from("reviewer"), platform.toBuffer(), reviewer2.publicKey.toBuffer()],
     program.programId
   )[0];
 
   let reviewer3Account = PublicKey.findProgramAddressSync(
-    [Buffer.from("reviewer"), reviewer3.publicKey.toBuffer()],
+    [Buffer.from("reviewer"), platform.toBuffer(), reviewer3.publicKey.toBuffer()],
     program.programId
   )[0];
 
@@ -134,264 +170,1900 @@ describe("realvue", () => {
     program.programId
   )[0];
 
+  // Bond vault PDAs (one per review)
+  let bond1Vault = PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), review1Account.toBuffer()],
+    program.programId
+  )[0];
 
+  let bond2Vault = PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), review2Account.toBuffer()],
+    program.programId
+  )[0];
 
+  let bond3Vault = PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), review3Account.toBuffer()],
+    program.programId
+  )[0];
 
-  it("Platform Initialization, Should initialize platform successfully", async () => {
-    const tx = await program.methods.initPlatform(seed, PLATFORM_FEE)
-      .accountsPartial({
-        admin: admin.publicKey,
-        platform: platform,
-        revMint: revMint,
-        treasury: treasury,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([admin])
-      .rpc();
-    console.log("Initialize platform tx:", tx);
+  // Purchase record PDAs, seeded by sha256(campaign || tx_id)
+  const purchaseRecordFor = (campaignKey: PublicKey, txId: string) => PublicKey.findProgramAddressSync(
+    [Buffer.from("purchase"), Buffer.from(sha256(Buffer.concat([campaignKey.toBuffer(), Buffer.from(txId)])))],
+    program.programId
+  )[0];
 
-    merchantAta = (await getOrCreateAssociatedTokenAccount(
-      connection,
-      merchant,
-      revMint,
-      merchant.publicKey,
-      true
-    )).address;
+  let purchase1Record = purchaseRecordFor(campaign, TX_IDS.TX1);
+  let purchase2Record = purchaseRecordFor(campaign, TX_IDS.TX2);
+  let purchase3Record = purchaseRecordFor(campaign, TX_IDS.TX3);
 
-    console.log(`Merchant ATA for revMint token is: ${merchantAta.toBase58()}`);
-  });
+  // A platform the merchant administers, campaigns created under the main one must reject it
+  const merchantPlatform = PublicKey.findProgramAddressSync(
+    [Buffer.from("realvue"), seed.toBuffer("le", 8), merchant.publicKey.toBuffer()],
+    program.programId
+  )[0];
 
+  // approve_review recounts passing attestations from these
+  const attestationAccounts = (reviewAccount: PublicKey) => [{
+    pubkey: PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), reviewAccount.toBuffer(), attestor.publicKey.toBuffer()],
+      program.programId
+    )[0],
+    isSigner: false,
+    isWritable: false,
+  }];
+
+  const vaultFor = (campaignKey: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("campaign_vault"), campaignKey.toBuffer()],
+    program.programId
+  )[0];
 
-  it("Campaign Management, Should create campaign successfully", async () => {
-    const tx = await program.methods.createCampaign(CAMPAIGN_NAME, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED)
+  const reviewerAccountFor = (reviewer: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("reviewer"), platform.toBuffer(), reviewer.toBuffer()],
+    program.programId
+  )[0];
+
+  const reviewFor = (campaignKey: PublicKey, reviewer: PublicKey) => PublicKey.findProgramAddressSync(
+    [campaignKey.toBuffer(), reviewer.toBuffer()],
+    program.programId
+  )[0];
+
+  const bondVaultFor = (reviewAccount: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), reviewAccount.toBuffer()],
+    program.programId
+  )[0];
+
+  // Reward of one approved review, the deposit minus the snapshotted fee split over the reviews needed
+  const rewardFor = (campaignAccount: any) => {
+    const fee = campaignAccount.depositAmount.muln(campaignAccount.feeBps).divn(10000);
+    return campaignAccount.depositAmount.sub(fee).divn(campaignAccount.reviewsNeeded).toNumber();
+  };
+
+  // Campaign, review and moderation steps for the behaviour tests that run on campaigns of their own
+  const createCampaign = async (name: string, vesting: any = null) => {
+    const newCampaign = await nextCampaign();
+
+    await program.methods
+      .createCampaign(name, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, vesting)
       .accountsPartial({
         merchant: merchant.publicKey,
-        campaign: campaign,
+        merchantCounter: merchantCounter,
+        campaign: newCampaign,
+        merchantAccount: merchantAccount,
         merchantAta: merchantAta,
         platform: platform,
         revMint: revMint,
-        vault: campaignVault,
+        vault: vaultFor(newCampaign),
         treasury: treasury,
+        category: category,
+        referrerAccount: null,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([merchant])
       .rpc();
-    console.log("Create campaign tx:", tx);
-  });
 
+    return newCampaign;
+  };
 
-  it("Should create all reviewer accounts successfully", async () => {
-    // Create reviewer1 account
-    const r1tx = await program.methods.initReviewer()
-      .accountsPartial({
-        reviewer: reviewer1.publicKey,
-        reviewerAccount: reviewer1Account,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([reviewer1])
-      .rpc();
-    console.log("Reviewer1 account tx:", r1tx);
+  const submitReview = async (campaignKey: PublicKey, reviewer: Keypair, txId: string) => {
+    const reviewAccount = reviewFor(campaignKey, reviewer.publicKey);
 
-    // Create reviewer2 account
-    const r2tx = await program.methods.initReviewer()
+    await program.methods
+      .makeReview(REVIEW_DESCRIPTION1, txId)
       .accountsPartial({
-        reviewer: reviewer2.publicKey,
-        reviewerAccount: reviewer2Account,
+        payer: reviewer.publicKey,
+        reviewer: reviewer.publicKey,
+        reviewAccount: reviewAccount,
+        campaign: campaignKey,
+        vault: vaultFor(campaignKey),
+        reviewerAccount: reviewerAccountFor(reviewer.publicKey),
+        platform: platform,
+        category: category,
+        bondVault: bondVaultFor(reviewAccount),
+        purchaseRecord: purchaseRecordFor(campaignKey, txId),
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer2])
+      .signers([reviewer])
       .rpc();
-    console.log("Reviewer2 account tx:", r2tx);
 
-    // Create reviewer3 account
-    const r3tx = await program.methods.initReviewer()
+    return reviewAccount;
+  };
+
+  // A passing verdict from the platform's attestor, its threshold is one
+  const attest = async (reviewAccount: PublicKey) => {
+    const message = Buffer.concat([reviewAccount.toBuffer(), Buffer.from([1, 95])]);
+
+    await program.methods
+      .attestReview(attestor.publicKey, true, 95)
       .accountsPartial({
-        reviewer: reviewer3.publicKey,
-        reviewerAccount: reviewer3Account,
+        payer: admin.publicKey,
+        attestation: attestationAccounts(reviewAccount)[0].pubkey,
+        reviewAccount: reviewAccount,
+        platform: platform,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer3])
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: attestor.secretKey,
+          message,
+        }),
+      ])
+      .signers([admin])
       .rpc();
-    console.log("Reviewer3 account tx:", r3tx);
-
-    console.log("All reviewer accounts created successfully");
-  });
+  };
 
+  const moderateReview = async (campaignKey: PublicKey, reviewer: PublicKey, txId: string, approved: boolean, reason: string) => {
+    const reviewAccount = reviewFor(campaignKey, reviewer);
 
-  it("Should submit multiple reviews successfully", async () => {
-    // Reviewer 1 submits positive review
-    const tx1 = await program.methods
-      .makeReview(REVIEW_DESCRIPTION1, TX_IDS.TX1)
+    await program.methods
+      .approveReview(approved, reason)
       .accountsPartial({
-        reviewer: reviewer1.publicKey,
-        reviewAccount: review1Account,
-        campaign: campaign,
-        reviewerAccount: reviewer1Account,
+        admin: admin.publicKey,
+        reviewAccount: reviewAccount,
+        campaign: campaignKey,
+        reviewerAccount: reviewerAccountFor(reviewer),
         platform: platform,
+        bondVault: bondVaultFor(reviewAccount),
+        reviewer: reviewer,
+        merchant: merchant.publicKey,
+        treasury: treasury,
+        purchaseRecord: purchaseRecordFor(campaignKey, txId),
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer1])
+      .remainingAccounts(attestationAccounts(reviewAccount))
+      .signers([admin])
       .rpc();
+  };
 
-    // Reviewer 2 submits review
-    const tx2 = await program.methods
-      .makeReview(REVIEW_DESCRIPTION2, TX_IDS.TX2)
+  const claimSolReward = (campaignKey: PublicKey, reviewer: Keypair) =>
+    program.methods
+      .claimReward({ sol: {} })
       .accountsPartial({
-        reviewer: reviewer2.publicKey,
-        reviewAccount: review2Account,
-        campaign: campaign,
-        reviewerAccount: reviewer2Account,
+        reviewer: reviewer.publicKey,
+        reviewAccount: reviewFor(campaignKey, reviewer.publicKey),
+        campaign: campaignKey,
+        vault: vaultFor(campaignKey),
+        reviewerAccount: reviewerAccountFor(reviewer.publicKey),
         platform: platform,
+        sponsor: null,
+        revMint: null,
+        reviewerRevAccount: null,
+        tokenProgram: null,
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer2])
+      .signers([reviewer])
       .rpc();
 
-    // Reviewer 3 submits review
-    const tx3 = await program.methods
-      .makeReview(REVIEW_DESCRIPTION3, TX_IDS.TX3)
+  const withdrawVested = (campaignKey: PublicKey, reviewer: Keypair) =>
+    program.methods
+      .withdrawVested()
       .accountsPartial({
-        reviewer: reviewer3.publicKey,
-        reviewAccount: review3Account,
-        campaign: campaign,
-        reviewerAccount: reviewer3Account,
-        platform: platform,
+        reviewer: reviewer.publicKey,
+        reviewAccount: reviewFor(campaignKey, reviewer.publicKey),
+        campaign: campaignKey,
+        vault: vaultFor(campaignKey),
+        reviewerAccount: reviewerAccountFor(reviewer.publicKey),
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer3])
+      .signers([reviewer])
       .rpc();
 
-    console.log("All reviews submitted successfully");
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
 
-    // Verify all review accounts
-    const review1 = await program.account.reviewAccount.fetch(review1Account);
-    const review2 = await program.account.reviewAccount.fetch(review2Account);
-    const review3 = await program.account.reviewAccount.fetch(review3Account);
 
-    expect(review1.description).to.equal(REVIEW_DESCRIPTION1);
-    expect(review2.description).to.equal(REVIEW_DESCRIPTION2);
-    expect(review3.description).to.equal(REVIEW_DESCRIPTION3);
-  });
 
-  it("Should approve multiple reviews successfully", async () => {
-    // Approve all three reviews
-    await program.methods
-      .approveReview(true, FLAGGED_REASONS.NONE)
+
+  it("Platform Initialization, Should initialize platform successfully", async () => {
+    const tx = await program.methods.initPlatform(seed, PLATFORM_FEE)
       .accountsPartial({
         admin: admin.publicKey,
-        reviewAccount: review1Account,
-        campaign: campaign,
-        reviewerAccount: reviewer1Account,
         platform: platform,
+        revMint: revMint,
+        treasury: treasury,
+        tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([admin])
       .rpc();
+    console.log("Initialize platform tx:", tx);
+
+    merchantAta = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      merchant,
+      revMint,
+      merchant.publicKey,
+      true
+    )).address;
+
+    console.log(`Merchant ATA for revMint token is: ${merchantAta.toBase58()}`);
+  });
+
+
+  it("Platform Initialization, Should initialize a platform the merchant administers", async () => {
+    await program.methods.initPlatform(seed, PLATFORM_FEE)
+      .accountsPartial({
+        admin: merchant.publicKey,
+        platform: merchantPlatform,
+        revMint: PublicKey.findProgramAddressSync([Buffer.from('rev'), merchantPlatform.toBuffer()], program.programId)[0],
+        treasury: PublicKey.findProgramAddressSync([Buffer.from("treasury"), merchantPlatform.toBuffer()], program.programId)[0],
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
 
     await program.methods
-      .approveReview(true, FLAGGED_REASONS.NONE)
+      .setReviewSponsor(merchant.publicKey)
+      .accountsPartial({
+        admin: merchant.publicKey,
+        platform: merchantPlatform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const platformAccount = await program.account.platformConfig.fetch(merchantPlatform);
+    expect(platformAccount.reviewSponsor.toBase58()).to.equal(merchant.publicKey.toBase58());
+  });
+
+  it("Admin Function, Should migrate platform to the current version", async () => {
+    const tx = await program.methods
+      .migratePlatform()
       .accountsPartial({
         admin: admin.publicKey,
-        reviewAccount: review2Account,
-        campaign: campaign,
-        reviewerAccount: reviewer2Account,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
       .signers([admin])
       .rpc();
+    console.log("Migrate platform tx:", tx);
 
-    await program.methods
-      .approveReview(true, FLAGGED_REASONS.NONE)
+    const platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.version).to.equal(6);
+  });
+
+  it("Admin Function, Should set volume fee tiers", async () => {
+    const tx = await program.methods
+      .setFeeTiers([
+        { minVolume: new BN(10 * LAMPORTS_PER_SOL), feeBps: 400 },
+        { minVolume: new BN(100 * LAMPORTS_PER_SOL), feeBps: 250 },
+      ])
       .accountsPartial({
         admin: admin.publicKey,
-        reviewAccount: review3Account,
-        campaign: campaign,
-        reviewerAccount: reviewer3Account,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
       .signers([admin])
       .rpc();
+    console.log("Set fee tiers tx:", tx);
 
-    // Verify campaign approved count
-    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
-    expect(campaignAccount.approvedCount).to.equal(3);
+    const platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.feeTiers.length).to.equal(2);
   });
 
-  xit("Should allow all reviewers to claim rewards", async () => {
+  it("Should fail to set unsorted fee tiers", async () => {
+    try {
+      await program.methods
+        .setFeeTiers([
+          { minVolume: new BN(100 * LAMPORTS_PER_SOL), feeBps: 250 },
+          { minVolume: new BN(10 * LAMPORTS_PER_SOL), feeBps: 400 },
+        ])
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
 
-    // Wait a bit for transactions to settle
-    await new Promise(resolve => setTimeout(resolve, 2000));
-    // All reviewers claim their rewards
+      expect.fail("Should have failed - unsorted tiers");
+    } catch (error) {
+      expect(error.message).to.include("InvalidFeeTiers");
+    }
+  });
+
+
+  it("Referral, Should register referrer and set referral share", async () => {
     await program.methods
-      .claimReward()
+      .setReferralShare(2000) // 20% of the platform fee
       .accountsPartial({
-        reviewer: reviewer1.publicKey,
-        reviewAccount: review1Account,
-        campaign: campaign,
-        vault: campaignVault,
-        reviewerAccount: reviewer1Account,
+        admin: admin.publicKey,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer1])
+      .signers([admin])
       .rpc();
 
-    await program.methods
-      .claimReward()
+    const tx = await program.methods
+      .registerReferrer()
       .accountsPartial({
-        reviewer: reviewer2.publicKey,
-        reviewAccount: review2Account,
-        campaign: campaign,
-        vault: campaignVault,
-        reviewerAccount: reviewer2Account,
+        referrer: reviewer3.publicKey,
+        category: category,
+        referrerAccount: referrerAccount,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer2])
+      .signers([reviewer3])
       .rpc();
+    console.log("Register referrer tx:", tx);
+  });
+
+  it("Admin Function, Should create a campaign category", async () => {
+    const policy = {
+      feeBps: null,
+      minDeposit: new BN(0.1 * LAMPORTS_PER_SOL),
+      minReviewerRank: 0,
+      maxDescriptionLen: 500,
+    };
 
     await program.methods
-      .claimReward()
+      .createCategory(CATEGORY_NAME, policy)
       .accountsPartial({
-        reviewer: reviewer3.publicKey,
-        reviewAccount: review3Account,
-        campaign: campaign,
-        vault: campaignVault,
-        reviewerAccount: reviewer3Account,
+        admin: admin.publicKey,
+        category: category,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
-      .signers([reviewer3])
+      .signers([admin])
       .rpc();
 
-    console.log("All reviewers claimed rewards successfully");
-
-    // Verify all rewards were claimed
-    const review1 = await program.account.reviewAccount.fetch(review1Account);
-    const review2 = await program.account.reviewAccount.fetch(review2Account);
-    const review3 = await program.account.reviewAccount.fetch(review3Account);
+    const categoryAccount = await program.account.category.fetch(category);
+    expect(categoryAccount.name).to.equal(CATEGORY_NAME);
+    expect(categoryAccount.active).to.be.true;
 
-    expect(review1.rewardClaimed).to.be.true;
-    expect(review2.rewardClaimed).to.be.true;
-    expect(review3.rewardClaimed).to.be.true;
+    // Descriptions can't outgrow what a review account stores
+    try {
+      await program.methods
+        .updateCategory({ ...policy, maxDescriptionLen: 501 }, true)
+        .accountsPartial({
+          admin: admin.publicKey,
+          category: category,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("Should have thrown error");
+    } catch (error) {
+      expect(error.message).to.include("InvalidCategoryPolicy");
+    }
+  });
+
+  it("Campaign Management, Should create campaign successfully", async () => {
+    const tx = await program.methods.createCampaign(CAMPAIGN_NAME, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
+        campaign: campaign,
+        merchantAccount: merchantAccount,
+        merchantAta: merchantAta,
+        platform: platform,
+        revMint: revMint,
+        vault: campaignVault,
+        treasury: treasury,
+        category: category,
+        referrerAccount: referrerAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("Create campaign tx:", tx);
+
+    // First campaign is below every tier, so the base fee is snapshotted
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.feeBps).to.equal(PLATFORM_FEE);
+    expect(campaignAccount.campaignId.toNumber()).to.equal(1);
+    expect(campaignAccount.platform.toBase58()).to.equal(platform.toBase58());
+
+    const merchantState = await program.account.merchantAccount.fetch(merchantAccount);
+    expect(merchantState.totalDeposited.toString()).to.equal(DEPOSIT_AMOUNT.toString());
+  });
+
+
+  it("Campaign Management, Should rename a campaign without moving it", async () => {
+    await program.methods
+      .renameCampaign("Crypto Art, Season Two")
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: campaign,
+      })
+      .signers([merchant])
+      .rpc();
+
+    let campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.name).to.equal("Crypto Art, Season Two");
+
+    try {
+      await program.methods
+        .renameCampaign("x".repeat(33))
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          campaign: campaign,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - name longer than 32 characters");
+    } catch (error) {
+      expect(error.message).to.include("InvalidCampaignName");
+    }
+
+    await program.methods
+      .renameCampaign(CAMPAIGN_NAME)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: campaign,
+      })
+      .signers([merchant])
+      .rpc();
+
+    campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.name).to.equal(CAMPAIGN_NAME);
+  });
+
+  it("Referral, Should let referrer claim their fee share", async () => {
+    const referrerState = await program.account.referrerAccount.fetch(referrerAccount);
+    const expectedShare = DEPOSIT_AMOUNT.muln(PLATFORM_FEE).divn(10000).muln(2000).divn(10000);
+    expect(referrerState.pendingRewards.toString()).to.equal(expectedShare.toString());
+
+    const tx = await program.methods
+      .claimReferral()
+      .accountsPartial({
+        referrer: reviewer3.publicKey,
+        category: category,
+        referrerAccount: referrerAccount,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer3])
+      .rpc();
+    console.log("Claim referral tx:", tx);
+
+    const referrerAfter = await program.account.referrerAccount.fetch(referrerAccount);
+    expect(referrerAfter.pendingRewards.toNumber()).to.equal(0);
+    expect(referrerAfter.totalEarned.toString()).to.equal(expectedShare.toString());
+  });
+
+
+  it("Should fail to create campaign with a negative vesting cliff", async () => {
+    const vestedName = "Vested Campaign";
+    const vestedCampaign = await nextCampaign();
+    const vestedVault = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_vault"), vestedCampaign.toBuffer()],
+      program.programId
+    )[0];
+
+    try {
+      await program.methods
+        .createCampaign(vestedName, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, {
+          cliff: new BN(-1),
+          duration: new BN(3600),
+          clawbackWindow: new BN(3600),
+        })
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          merchantCounter: merchantCounter,
+          campaign: vestedCampaign,
+          merchantAccount: merchantAccount,
+          merchantAta: merchantAta,
+          platform: platform,
+          revMint: revMint,
+          vault: vestedVault,
+          treasury: treasury,
+          category: category,
+          referrerAccount: null,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - invalid vesting schedule");
+    } catch (error) {
+      expect(error.message).to.include("InvalidVestingSchedule");
+    }
+  });
+
+  it("Admin Function, Should set the cancellation policy", async () => {
+    await program.methods
+      .setCancellationPolicy(new BN(3600), 2000)
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.cancellationGracePeriod.toNumber()).to.equal(3600);
+    expect(platformAccount.killFeeBps).to.equal(2000);
+
+    try {
+      await program.methods
+        .setCancellationPolicy(new BN(3600), 10001)
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("Should have thrown error");
+    } catch (error) {
+      expect(error.message).to.include("InvalidCancellationPolicy");
+    }
+  });
+
+  it("Campaign Management, Should cancel campaign and hold the refund for the grace period", async () => {
+    const cancelledName = "Cancelled Campaign";
+    const cancelledCampaign = await nextCampaign();
+    const cancelledVault = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_vault"), cancelledCampaign.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .createCampaign(cancelledName, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
+        campaign: cancelledCampaign,
+        merchantAccount: merchantAccount,
+        merchantAta: merchantAta,
+        platform: platform,
+        revMint: revMint,
+        vault: cancelledVault,
+        treasury: treasury,
+        category: category,
+        referrerAccount: null,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    // The merchant's own platform has no grace period or kill fee, it can't stand in
+    try {
+      await program.methods
+        .cancelCampaign()
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          campaign: cancelledCampaign,
+          platform: merchantPlatform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - the campaign was created under another platform");
+    } catch (error) {
+      expect(error.message).to.include("CampaignPlatformMismatch");
+    }
+
+    await program.methods
+      .cancelCampaign()
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: cancelledCampaign,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(cancelledCampaign);
+    expect(campaignAccount.active).to.be.false;
+    expect(campaignAccount.cancelled).to.be.true;
+    expect(campaignAccount.killFee.toNumber()).to.be.greaterThan(0);
+
+    // Pending reviews get moderated before the merchant sees the remainder
+    try {
+      await program.methods
+        .refundDeposit()
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          campaign: cancelledCampaign,
+          vault: cancelledVault,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      expect.fail("Should have thrown error");
+    } catch (error) {
+      expect(error.message).to.include("GracePeriodActive");
+    }
+  });
+
+  it("Should create all reviewer accounts successfully", async () => {
+    // Create reviewer1 account
+    const r1tx = await program.methods.initReviewer()
+      .accountsPartial({
+        payer: reviewer1.publicKey,
+        reviewer: reviewer1.publicKey,
+        reviewerAccount: reviewer1Account,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer1])
+      .rpc();
+    console.log("Reviewer1 account tx:", r1tx);
+
+    // Create reviewer2 account
+    const r2tx = await program.methods.initReviewer()
+      .accountsPartial({
+        payer: reviewer2.publicKey,
+        reviewer: reviewer2.publicKey,
+        reviewerAccount: reviewer2Account,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer2])
+      .rpc();
+    console.log("Reviewer2 account tx:", r2tx);
+
+    // Create reviewer3 account
+    const r3tx = await program.methods.initReviewer()
+      .accountsPartial({
+        payer: reviewer3.publicKey,
+        reviewer: reviewer3.publicKey,
+        reviewerAccount: reviewer3Account,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer3])
+      .rpc();
+    console.log("Reviewer3 account tx:", r3tx);
+
+    console.log("All reviewer accounts created successfully");
+  });
+
+
+  it("Migrations, Should migrate a campaign and review from the first deployment", async () => {
+    // Loaded from tests/fixtures with the baseline layouts, the keys are fixed so the addresses are too
+    const legacyMerchant = Keypair.fromSeed(new Uint8Array(32).fill(1));
+    const legacyReviewer = Keypair.fromSeed(new Uint8Array(32).fill(2));
+    const legacyPendingReviewer = Keypair.fromSeed(new Uint8Array(32).fill(3));
+    const legacyCampaign = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), Buffer.from("Legacy Campaign"), legacyMerchant.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const legacyReview = reviewFor(legacyCampaign, legacyReviewer.publicKey);
+    const legacyPendingReview = reviewFor(legacyCampaign, legacyPendingReviewer.publicKey);
+
+    const migrateCampaign = (reviews: PublicKey[]) =>
+      program.methods
+        .migrateCampaign()
+        .accountsPartial({
+          payer: provider.wallet.publicKey,
+          campaign: legacyCampaign,
+          platform: platform,
+          category: null,
+          merchant: legacyMerchant.publicKey,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(reviews.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+        .signers([legacyMerchant, admin])
+        .rpc();
+
+    // The approved review has to be passed, otherwise its reward couldn't be told apart from a claimed one
+    try {
+      await migrateCampaign([legacyPendingReview]);
+      expect.fail("Should have failed - the approved review is missing");
+    } catch (error) {
+      expect(error.message).to.include("MigrationReviewMismatch");
+    }
+
+    const sizeBefore = (await connection.getAccountInfo(legacyCampaign)).data.length;
+    await migrateCampaign([legacyReview, legacyPendingReview]);
+
+    const migrated = await program.account.reviewCampaign.fetch(legacyCampaign);
+    expect((await connection.getAccountInfo(legacyCampaign)).data.length).to.be.greaterThan(sizeBefore);
+    expect(migrated.version).to.equal(8);
+    expect(migrated.name).to.equal("Legacy Campaign");
+    expect(migrated.merchant.toBase58()).to.equal(legacyMerchant.publicKey.toBase58());
+    expect(migrated.depositAmount.toString()).to.equal(DEPOSIT_AMOUNT.toString());
+    expect(migrated.feeBps).to.equal(PLATFORM_FEE);
+    expect(migrated.approvedCount).to.equal(1);
+    expect(migrated.pendingReviews).to.equal(1);
+    expect(migrated.rewardsClaimed).to.equal(0);
+    expect(migrated.campaignId.toNumber()).to.equal(0);
+    expect(migrated.platform.toBase58()).to.equal(platform.toBase58());
+
+    await program.methods
+      .migrateReview()
+      .accountsPartial({
+        payer: provider.wallet.publicKey,
+        reviewAccount: legacyReview,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const review = await program.account.reviewAccount.fetch(legacyReview);
+    expect(review.version).to.equal(2);
+    expect(review.reviewer.toBase58()).to.equal(legacyReviewer.publicKey.toBase58());
+    expect(review.approved).to.be.true;
+    expect(review.rewardClaimed).to.be.false;
+    expect(review.txId).to.equal("LegacyTx1");
+    expect(review.bond.toNumber()).to.equal(0);
+
+    // Once both are migrated the reward is paid like any other
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(legacyReviewer.publicKey, LAMPORTS_PER_SOL),
+      commitment
+    );
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: legacyReviewer.publicKey,
+        reviewer: legacyReviewer.publicKey,
+        reviewerAccount: reviewerAccountFor(legacyReviewer.publicKey),
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([legacyReviewer])
+      .rpc();
+
+    const reward = rewardFor(migrated);
+    const balanceBefore = await connection.getBalance(legacyReviewer.publicKey);
+    await claimSolReward(legacyCampaign, legacyReviewer);
+
+    expect(await connection.getBalance(legacyReviewer.publicKey)).to.equal(balanceBefore + reward);
+    expect((await program.account.reviewCampaign.fetch(legacyCampaign)).rewardsClaimed).to.equal(1);
+    expect((await program.account.reviewerAccount.fetch(reviewerAccountFor(legacyReviewer.publicKey))).totalEarned.toNumber())
+      .to.equal(reward);
+
+    try {
+      await claimSolReward(legacyCampaign, legacyReviewer);
+      expect.fail("Should have failed - the reward was already claimed");
+    } catch (error) {
+      expect(error.message).to.include("RewardAlreadyClaimed");
+    }
+  });
+
+  it("Campaign Management, Should require a review bond", async () => {
+    const tx = await program.methods
+      .setReviewBond(REVIEW_BOND, { treasury: {} })
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: campaign,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("Set review bond tx:", tx);
+
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.bondAmount.toString()).to.equal(REVIEW_BOND.toString());
+  });
+
+  it("Should fail when platform trusts its own reviewer ranks", async () => {
+    try {
+      await program.methods
+        .setTrustedPlatforms([platform])
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - platform cannot trust itself");
+    } catch (error) {
+      expect(error.message).to.include("UntrustedPlatform");
+    }
+  });
+
+  it("Should submit multiple reviews successfully", async () => {
+    // Reviewer 1 submits positive review
+    const tx1 = await program.methods
+      .makeReview(REVIEW_DESCRIPTION1, TX_IDS.TX1)
+      .accountsPartial({
+        payer: reviewer1.publicKey,
+        reviewer: reviewer1.publicKey,
+        reviewAccount: review1Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer1Account,
+        platform: platform,
+        category: category,
+        bondVault: bond1Vault,
+        purchaseRecord: purchase1Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer1])
+      .rpc();
+
+    // Reviewer 2 submits review
+    const tx2 = await program.methods
+      .makeReview(REVIEW_DESCRIPTION2, TX_IDS.TX2)
+      .accountsPartial({
+        payer: reviewer2.publicKey,
+        reviewer: reviewer2.publicKey,
+        reviewAccount: review2Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer2Account,
+        platform: platform,
+        category: category,
+        bondVault: bond2Vault,
+        purchaseRecord: purchase2Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer2])
+      .rpc();
+
+    // Reviewer 3 submits review
+    const tx3 = await program.methods
+      .makeReview(REVIEW_DESCRIPTION3, TX_IDS.TX3)
+      .accountsPartial({
+        payer: reviewer3.publicKey,
+        reviewer: reviewer3.publicKey,
+        reviewAccount: review3Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer3Account,
+        platform: platform,
+        category: category,
+        bondVault: bond3Vault,
+        purchaseRecord: purchase3Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer3])
+      .rpc();
+
+    console.log("All reviews submitted successfully");
+
+    // Verify all review accounts
+    const review1 = await program.account.reviewAccount.fetch(review1Account);
+    const review2 = await program.account.reviewAccount.fetch(review2Account);
+    const review3 = await program.account.reviewAccount.fetch(review3Account);
+
+    // Fresh reviewers are Bronze, so they post the full bond
+    expect(review1.bond.toString()).to.equal(REVIEW_BOND.toString());
+
+    expect(review1.description).to.equal(REVIEW_DESCRIPTION1);
+    expect(review2.description).to.equal(REVIEW_DESCRIPTION2);
+    expect(review3.description).to.equal(REVIEW_DESCRIPTION3);
+
+    const record = await program.account.purchaseRecord.fetch(purchase1Record);
+    expect(record.review.toBase58()).to.equal(review1Account.toBase58());
+  });
+
+  it("Should fail when a purchase transaction backs a second review", async () => {
+    const reviewer4 = Keypair.generate();
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(reviewer4.publicKey, LAMPORTS_PER_SOL),
+      commitment
+    );
+
+    const reviewer4Account = PublicKey.findProgramAddressSync(
+      [Buffer.from("reviewer"), platform.toBuffer(), reviewer4.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const review4Account = PublicKey.findProgramAddressSync(
+      [campaign.toBuffer(), reviewer4.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: reviewer4.publicKey,
+        reviewer: reviewer4.publicKey,
+        reviewerAccount: reviewer4Account,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer4])
+      .rpc();
+
+    try {
+      await program.methods
+        .makeReview(REVIEW_DESCRIPTION2, TX_IDS.TX1)
+        .accountsPartial({
+          payer: reviewer4.publicKey,
+          reviewer: reviewer4.publicKey,
+          reviewAccount: review4Account,
+          campaign: campaign,
+          vault: campaignVault,
+          reviewerAccount: reviewer4Account,
+          platform: platform,
+          category: category,
+          bondVault: PublicKey.findProgramAddressSync(
+            [Buffer.from("bond"), review4Account.toBuffer()],
+            program.programId
+          )[0],
+          purchaseRecord: purchase1Record,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reviewer4])
+        .rpc();
+
+      expect.fail("Should have failed - TX1 already backs reviewer1's review");
+    } catch (error) {
+      expect(error.message).to.include("DuplicatePurchaseProof");
+    }
+  });
+
+  it("Sponsored Reviews, Should front rent for a reviewer holding no SOL", async () => {
+    const sponsoredName = "Sponsored Campaign";
+    const sponsoredCampaign = await nextCampaign();
+    const sponsoredVault = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_vault"), sponsoredCampaign.toBuffer()],
+      program.programId
+    )[0];
+
+    // Admin doubles as the sponsor here, the reviewer only signs for authorship
+    const reviewer5 = Keypair.generate();
+    const reviewer5Account = PublicKey.findProgramAddressSync(
+      [Buffer.from("reviewer"), platform.toBuffer(), reviewer5.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const review5Account = PublicKey.findProgramAddressSync(
+      [sponsoredCampaign.toBuffer(), reviewer5.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .setReviewSponsor(admin.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createCampaign(sponsoredName, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
+        campaign: sponsoredCampaign,
+        merchantAccount: merchantAccount,
+        merchantAta: merchantAta,
+        platform: platform,
+        revMint: revMint,
+        vault: sponsoredVault,
+        treasury: treasury,
+        category: category,
+        referrerAccount: null,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const RENT_BUDGET = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+    await program.methods
+      .fundRentBudget(RENT_BUDGET)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: sponsoredCampaign,
+        vault: sponsoredVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: admin.publicKey,
+        reviewer: reviewer5.publicKey,
+        reviewerAccount: reviewer5Account,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin, reviewer5])
+      .rpc();
+
+    // No campaign to charge yet, so the reviewer account rent is owed out of the first reward
+    const reviewerRent = await connection.getBalance(reviewer5Account);
+    let reviewerState = await program.account.reviewerAccount.fetch(reviewer5Account);
+    expect(reviewerState.rentDebt.toNumber()).to.equal(reviewerRent);
+
+    // The merchant sponsors on their own platform, that can't reach this campaign's rent budget
+    const merchantPlatformReviewer = PublicKey.findProgramAddressSync(
+      [Buffer.from("reviewer"), merchantPlatform.toBuffer(), reviewer5.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: merchant.publicKey,
+        reviewer: reviewer5.publicKey,
+        reviewerAccount: merchantPlatformReviewer,
+        platform: merchantPlatform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant, reviewer5])
+      .rpc();
+
+    try {
+      await program.methods
+        .makeReview(REVIEW_DESCRIPTION1, TX_IDS.TX1)
+        .accountsPartial({
+          payer: merchant.publicKey,
+          reviewer: reviewer5.publicKey,
+          reviewAccount: review5Account,
+          campaign: sponsoredCampaign,
+          vault: sponsoredVault,
+          reviewerAccount: merchantPlatformReviewer,
+          platform: merchantPlatform,
+          category: category,
+          bondVault: PublicKey.findProgramAddressSync(
+            [Buffer.from("bond"), review5Account.toBuffer()],
+            program.programId
+          )[0],
+          purchaseRecord: purchaseRecordFor(sponsoredCampaign, TX_IDS.TX1),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant, reviewer5])
+        .rpc();
+
+      expect.fail("Should have failed - the campaign was created under another platform");
+    } catch (error) {
+      expect(error.message).to.include("CampaignPlatformMismatch");
+    }
+
+    await program.methods
+      .makeReview(REVIEW_DESCRIPTION1, TX_IDS.TX1)
+      .accountsPartial({
+        payer: admin.publicKey,
+        reviewer: reviewer5.publicKey,
+        reviewAccount: review5Account,
+        campaign: sponsoredCampaign,
+        vault: sponsoredVault,
+        reviewerAccount: reviewer5Account,
+        platform: platform,
+        category: category,
+        bondVault: PublicKey.findProgramAddressSync(
+          [Buffer.from("bond"), review5Account.toBuffer()],
+          program.programId
+        )[0],
+        purchaseRecord: purchaseRecordFor(sponsoredCampaign, TX_IDS.TX1),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin, reviewer5])
+      .rpc();
+
+    // The campaign's budget reimbursed the review rent, nothing new is owed
+    const reviewRent = (await connection.getBalance(review5Account))
+      + (await connection.getBalance(purchaseRecordFor(sponsoredCampaign, TX_IDS.TX1)));
+    const campaignAccount = await program.account.reviewCampaign.fetch(sponsoredCampaign);
+    expect(campaignAccount.rentBudget.toNumber()).to.equal(RENT_BUDGET.toNumber() - reviewRent);
+
+    reviewerState = await program.account.reviewerAccount.fetch(reviewer5Account);
+    expect(reviewerState.rentDebt.toNumber()).to.equal(reviewerRent);
+    expect(await connection.getBalance(reviewer5.publicKey)).to.equal(0);
+
+    // Only the platform's sponsor can pay for someone else
+    try {
+      await program.methods.initReviewer()
+        .accountsPartial({
+          payer: merchant.publicKey,
+          reviewer: reviewer5.publicKey,
+          reviewerAccount: reviewer5Account,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant, reviewer5])
+        .rpc();
+
+      expect.fail("Should have failed - merchant is not the review sponsor");
+    } catch (error) {
+      expect(error.message).to.include("UnauthorizedSponsor");
+    }
+
+    // Closing and re-initializing can't wipe the debt
+    try {
+      await program.methods.closeReviewer()
+        .accountsPartial({
+          reviewer: reviewer5.publicKey,
+          reviewerAccount: reviewer5Account,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reviewer5])
+        .rpc();
+
+      expect.fail("Should have failed - the reviewer still owes rent");
+    } catch (error) {
+      expect(error.message).to.include("RentDebtOutstanding");
+    }
+  });
+
+  it("Should attest reviews with ed25519 signatures", async () => {
+    await program.methods
+      .setAttestors([attestor.publicKey], 1)
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    for (const reviewAccount of [review1Account, review2Account, review3Account]) {
+      const verdict = true;
+      const score = 95;
+      const message = Buffer.concat([reviewAccount.toBuffer(), Buffer.from([verdict ? 1 : 0, score])]);
+
+      const attestation = PublicKey.findProgramAddressSync(
+        [Buffer.from("attestation"), reviewAccount.toBuffer(), attestor.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+      await program.methods
+        .attestReview(attestor.publicKey, verdict, score)
+        .accountsPartial({
+          payer: admin.publicKey,
+          attestation: attestation,
+          reviewAccount: reviewAccount,
+          platform: platform,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: attestor.secretKey,
+            message,
+          }),
+        ])
+        .signers([admin])
+        .rpc();
+    }
+
+    const review1 = await program.account.reviewAccount.fetch(review1Account);
+    expect(review1.passingAttestations).to.equal(1);
+  });
+
+  it("Should not count attestations from removed attestors", async () => {
+    const setAttestors = (attestors: PublicKey[]) =>
+      program.methods
+        .setAttestors(attestors, 1)
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+    await setAttestors([Keypair.generate().publicKey]);
+
+    try {
+      await program.methods
+        .approveReview(true, FLAGGED_REASONS.NONE)
+        .accountsPartial({
+          admin: admin.publicKey,
+          reviewAccount: review1Account,
+          campaign: campaign,
+          reviewerAccount: reviewer1Account,
+          platform: platform,
+          bondVault: bond1Vault,
+          reviewer: reviewer1.publicKey,
+          merchant: merchant.publicKey,
+          treasury: treasury,
+          purchaseRecord: purchase1Record,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(attestationAccounts(review1Account))
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - the attestor was removed");
+    } catch (error) {
+      expect(error.message).to.include("AttestationRequired");
+    }
+
+    await setAttestors([attestor.publicKey]);
+  });
+
+  it("Should approve multiple reviews successfully", async () => {
+    // Approve all three reviews
+    await program.methods
+      .approveReview(true, FLAGGED_REASONS.NONE)
+      .accountsPartial({
+        admin: admin.publicKey,
+        reviewAccount: review1Account,
+        campaign: campaign,
+        reviewerAccount: reviewer1Account,
+        platform: platform,
+        bondVault: bond1Vault,
+        reviewer: reviewer1.publicKey,
+        merchant: merchant.publicKey,
+        treasury: treasury,
+        purchaseRecord: purchase1Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(attestationAccounts(review1Account))
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .approveReview(true, FLAGGED_REASONS.NONE)
+      .accountsPartial({
+        admin: admin.publicKey,
+        reviewAccount: review2Account,
+        campaign: campaign,
+        reviewerAccount: reviewer2Account,
+        platform: platform,
+        bondVault: bond2Vault,
+        reviewer: reviewer2.publicKey,
+        merchant: merchant.publicKey,
+        treasury: treasury,
+        purchaseRecord: purchase2Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(attestationAccounts(review2Account))
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .approveReview(true, FLAGGED_REASONS.NONE)
+      .accountsPartial({
+        admin: admin.publicKey,
+        reviewAccount: review3Account,
+        campaign: campaign,
+        reviewerAccount: reviewer3Account,
+        platform: platform,
+        bondVault: bond3Vault,
+        reviewer: reviewer3.publicKey,
+        merchant: merchant.publicKey,
+        treasury: treasury,
+        purchaseRecord: purchase3Record,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(attestationAccounts(review3Account))
+      .signers([admin])
+      .rpc();
+
+    // Verify campaign approved count
+    const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAccount.approvedCount).to.equal(3);
+
+    // Bonds of approved reviews are returned in full
+    for (const bondVault of [bond1Vault, bond2Vault, bond3Vault]) {
+      expect(await provider.connection.getBalance(bondVault)).to.equal(0);
+    }
+  });
+
+  it("Review Bonds, Should slash the bond of a flagged review to the treasury", async () => {
+    const flaggedReviewer = Keypair.generate();
+    const FLAGGED_TX_ID = "3fG7hJ9kL2mN4pQ6rS8tU1vW3xY5zA7bC9dE2fG4hJ6kL8mN1pQ3rS5tU7vW9xY2zA4bC6dE8fG1hJ3kL5mN7p";
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(flaggedReviewer.publicKey, LAMPORTS_PER_SOL),
+      commitment
+    );
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: flaggedReviewer.publicKey,
+        reviewer: flaggedReviewer.publicKey,
+        reviewerAccount: reviewerAccountFor(flaggedReviewer.publicKey),
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([flaggedReviewer])
+      .rpc();
+
+    const flaggedReview = await submitReview(campaign, flaggedReviewer, FLAGGED_TX_ID);
+    const flaggedBondVault = bondVaultFor(flaggedReview);
+    const bondVaultBalance = await connection.getBalance(flaggedBondVault);
+    expect(bondVaultBalance).to.be.greaterThan(REVIEW_BOND.toNumber());
+
+    const treasuryBefore = await connection.getBalance(treasury);
+    const reviewerBefore = await connection.getBalance(flaggedReviewer.publicKey);
+    const campaignBefore = await program.account.reviewCampaign.fetch(campaign);
+
+    await moderateReview(campaign, flaggedReviewer.publicKey, FLAGGED_TX_ID, false, FLAGGED_REASONS.SPAM);
+
+    // The bond goes to the campaign's slash target, the vault's rent back to the reviewer
+    expect(await connection.getBalance(flaggedBondVault)).to.equal(0);
+    expect(await connection.getBalance(treasury)).to.equal(treasuryBefore + REVIEW_BOND.toNumber());
+    expect(await connection.getBalance(flaggedReviewer.publicKey))
+      .to.equal(reviewerBefore + bondVaultBalance - REVIEW_BOND.toNumber());
+
+    const review = await program.account.reviewAccount.fetch(flaggedReview);
+    expect(review.approved).to.be.false;
+    expect(review.flaggedReason).to.equal(FLAGGED_REASONS.SPAM);
+
+    const campaignAfter = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignAfter.approvedCount).to.equal(campaignBefore.approvedCount);
+    expect(campaignAfter.pendingReviews).to.equal(campaignBefore.pendingReviews - 1);
+
+    const reviewerState = await program.account.reviewerAccount.fetch(reviewerAccountFor(flaggedReviewer.publicKey));
+    expect(reviewerState.approvedCount).to.equal(0);
+
+    // A flagged review is settled, it can't be approved for its bond afterwards
+    try {
+      await moderateReview(campaign, flaggedReviewer.publicKey, FLAGGED_TX_ID, true, FLAGGED_REASONS.NONE);
+      expect.fail("Should have failed - the review was already flagged");
+    } catch (error) {
+      expect(error.message).to.include("ReviewAlreadyApproved");
+    }
+  });
+
+  it("Campaign Management, Should pay the kill fee for a review left pending after the grace period", async () => {
+    const setCancellationPolicy = (gracePeriod: number) =>
+      program.methods
+        .setCancellationPolicy(new BN(gracePeriod), 2000)
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+    // No grace period, the review is left pending as soon as the campaign is cancelled
+    await setCancellationPolicy(0);
+
+    const killedCampaign = await createCampaign("Killed Campaign");
+    const pendingReviewer = Keypair.generate();
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(pendingReviewer.publicKey, LAMPORTS_PER_SOL),
+      commitment
+    );
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: pendingReviewer.publicKey,
+        reviewer: pendingReviewer.publicKey,
+        reviewerAccount: reviewerAccountFor(pendingReviewer.publicKey),
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pendingReviewer])
+      .rpc();
+
+    const pendingReview = await submitReview(killedCampaign, pendingReviewer, TX_IDS.TX2);
+
+    await program.methods
+      .cancelCampaign()
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        campaign: killedCampaign,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    await setCancellationPolicy(3600);
+
+    let campaignState = await program.account.reviewCampaign.fetch(killedCampaign);
+    const killFee = Math.floor(rewardFor(campaignState) * 2000 / 10000);
+    expect(campaignState.killFee.toNumber()).to.equal(killFee);
+    expect(campaignState.pendingReviews).to.equal(1);
+
+    const claimKillFee = () =>
+      program.methods
+        .claimKillFee()
+        .accountsPartial({
+          reviewer: pendingReviewer.publicKey,
+          reviewAccount: pendingReview,
+          campaign: killedCampaign,
+          vault: vaultFor(killedCampaign),
+          bondVault: bondVaultFor(pendingReview),
+          reviewerAccount: reviewerAccountFor(pendingReviewer.publicKey),
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([pendingReviewer])
+        .rpc();
+
+    const vaultBefore = await connection.getBalance(vaultFor(killedCampaign));
+    const reviewerBefore = await connection.getBalance(pendingReviewer.publicKey);
+    await claimKillFee();
+
+    expect(await connection.getBalance(pendingReviewer.publicKey)).to.equal(reviewerBefore + killFee);
+    expect(await connection.getBalance(vaultFor(killedCampaign))).to.equal(vaultBefore - killFee);
+
+    const review = await program.account.reviewAccount.fetch(pendingReview);
+    expect(review.rewardClaimed).to.be.true;
+
+    campaignState = await program.account.reviewCampaign.fetch(killedCampaign);
+    expect(campaignState.pendingReviews).to.equal(0);
+
+    const reviewerState = await program.account.reviewerAccount.fetch(reviewerAccountFor(pendingReviewer.publicKey));
+    expect(reviewerState.totalEarned.toNumber()).to.equal(killFee);
+
+    try {
+      await claimKillFee();
+      expect.fail("Should have failed - the kill fee was already paid");
+    } catch (error) {
+      expect(error.message).to.include("ReviewNotPending");
+    }
+  });
+
+  it("Reputation, Should import approved reviews from a trusted platform", async () => {
+    const importedAccount = PublicKey.findProgramAddressSync(
+      [Buffer.from("reviewer"), merchantPlatform.toBuffer(), reviewer1.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .setTrustedPlatforms([platform])
+      .accountsPartial({
+        admin: merchant.publicKey,
+        platform: merchantPlatform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    await program.methods.initReviewer()
+      .accountsPartial({
+        payer: reviewer1.publicKey,
+        reviewer: reviewer1.publicKey,
+        reviewerAccount: importedAccount,
+        platform: merchantPlatform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer1])
+      .rpc();
+
+    const importReputation = (reviewerAccount: PublicKey, sourceReviewerAccount: PublicKey, targetPlatform: PublicKey) =>
+      program.methods
+        .importReputation()
+        .accountsPartial({
+          reviewer: reviewer1.publicKey,
+          reviewerAccount: reviewerAccount,
+          sourceReviewerAccount: sourceReviewerAccount,
+          platform: targetPlatform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reviewer1])
+        .rpc();
+
+    const source = await program.account.reviewerAccount.fetch(reviewer1Account);
+    expect(source.approvedCount).to.be.greaterThan(0);
+
+    await importReputation(importedAccount, reviewer1Account, merchantPlatform);
+
+    let imported = await program.account.reviewerAccount.fetch(importedAccount);
+    expect(imported.platform.toBase58()).to.equal(merchantPlatform.toBase58());
+    expect(imported.approvedCount).to.equal(source.approvedCount);
+    expect(imported.rank).to.equal(source.rank);
+    expect(imported.totalEarned.toNumber()).to.equal(0);
+
+    // Importing again takes the better record instead of adding it twice
+    await importReputation(importedAccount, reviewer1Account, merchantPlatform);
+
+    imported = await program.account.reviewerAccount.fetch(importedAccount);
+    expect(imported.approvedCount).to.equal(source.approvedCount);
+
+    // Trust is one way, the main platform doesn't trust the merchant's
+    try {
+      await importReputation(reviewer1Account, importedAccount, platform);
+      expect.fail("Should have failed - the source platform isn't trusted");
+    } catch (error) {
+      expect(error.message).to.include("UntrustedPlatform");
+    }
+  });
+
+  it("Should mint a soulbound rank badge", async () => {
+    const [badgeMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("badge"), reviewer1Account.toBuffer()],
+      program.programId
+    );
+    const badgeAta = getAssociatedTokenAddressSync(badgeMint, reviewer1.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    await program.methods
+      .syncRankBadge()
+      .accountsPartial({
+        payer: admin.publicKey,
+        reviewerAccount: reviewer1Account,
+        reviewer: reviewer1.publicKey,
+        badgeMint: badgeMint,
+        badgeAta: badgeAta,
+        platform: platform,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const reviewerAccount = await program.account.reviewerAccount.fetch(reviewer1Account);
+    expect(reviewerAccount.badgeRank).to.equal(reviewerAccount.rank);
+
+    const badge = await getAccount(connection, badgeAta, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(Number(badge.amount)).to.equal(1);
+
+    // Nothing to sync until the rank changes again
+    try {
+      await program.methods
+        .syncRankBadge()
+        .accountsPartial({
+          payer: admin.publicKey,
+          reviewerAccount: reviewer1Account,
+          reviewer: reviewer1.publicKey,
+          badgeMint: badgeMint,
+          badgeAta: badgeAta,
+          platform: platform,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("Should have thrown error");
+    } catch (error) {
+      expect(error.message).to.include("BadgeUpToDate");
+    }
+  });
+
+  it("Admin Function, Should set the REV payout premium", async () => {
+    try {
+      await program.methods
+        .setRevPayout(10001)
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - premium above 100%");
+    } catch (error) {
+      expect(error.message).to.include("InvalidRevPremium");
+    }
+
+    await program.methods
+      .setRevPayout(REV_PREMIUM_BPS)
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.revPayoutPremiumBps).to.equal(REV_PREMIUM_BPS);
+  });
+
+  it("Vesting, Should release a reward over time and claw back what hasn't vested", async () => {
+    const vestingCampaign = await createCampaign("Vesting Campaign", {
+      cliff: new BN(0),
+      duration: new BN(20),
+      clawbackWindow: new BN(3600),
+    });
+    const vestingReview = await submitReview(vestingCampaign, reviewer1, TX_IDS.TX1);
+    await attest(vestingReview);
+    await moderateReview(vestingCampaign, reviewer1.publicKey, TX_IDS.TX1, true, FLAGGED_REASONS.NONE);
+
+    const approvedCount = (await program.account.reviewerAccount.fetch(reviewer1Account)).approvedCount;
+
+    // The reward stays in the vault, locked away from the merchant's refund
+    await claimSolReward(vestingCampaign, reviewer1);
+
+    const reward = rewardFor(await program.account.reviewCampaign.fetch(vestingCampaign));
+    let review = await program.account.reviewAccount.fetch(vestingReview);
+    let campaignState = await program.account.reviewCampaign.fetch(vestingCampaign);
+    expect(review.rewardClaimed).to.be.true;
+    expect(review.vestingTotal.toNumber()).to.equal(reward);
+    expect(campaignState.vestingLocked.toNumber()).to.equal(reward);
+    expect(campaignState.rewardsClaimed).to.equal(1);
+
+    try {
+      await claimSolReward(vestingCampaign, reviewer1);
+      expect.fail("Should have failed - the reward was already claimed");
+    } catch (error) {
+      expect(error.message).to.include("RewardAlreadyClaimed");
+    }
+
+    // Part way through the schedule only the vested share is paid out
+    await sleep(3000);
+    const earnedBefore = (await program.account.reviewerAccount.fetch(reviewer1Account)).totalEarned.toNumber();
+    let balanceBefore = await connection.getBalance(reviewer1.publicKey);
+    await withdrawVested(vestingCampaign, reviewer1);
+
+    review = await program.account.reviewAccount.fetch(vestingReview);
+    const firstWithdrawal = review.vestingWithdrawn.toNumber();
+    expect(firstWithdrawal).to.be.greaterThan(0);
+    expect(firstWithdrawal).to.be.lessThan(reward);
+    expect(await connection.getBalance(reviewer1.publicKey)).to.equal(balanceBefore + firstWithdrawal);
+
+    let reviewerState = await program.account.reviewerAccount.fetch(reviewer1Account);
+    expect(reviewerState.totalEarned.toNumber()).to.equal(earnedBefore + firstWithdrawal);
+
+    // The clawback returns the unvested rest and overturns the approval
+    await sleep(2000);
+    await program.methods
+      .clawbackReward(FLAGGED_REASONS.FAKE)
+      .accountsPartial({
+        admin: admin.publicKey,
+        reviewAccount: vestingReview,
+        campaign: vestingCampaign,
+        platform: platform,
+        reviewerAccount: reviewer1Account,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    review = await program.account.reviewAccount.fetch(vestingReview);
+    campaignState = await program.account.reviewCampaign.fetch(vestingCampaign);
+    const keptReward = review.vestingTotal.toNumber();
+    expect(review.approved).to.be.false;
+    expect(review.flaggedReason).to.equal(FLAGGED_REASONS.FAKE);
+    expect(keptReward).to.be.greaterThan(firstWithdrawal);
+    expect(keptReward).to.be.lessThan(reward);
+    expect(campaignState.vestingLocked.toNumber()).to.equal(keptReward - firstWithdrawal);
+    expect(campaignState.approvedCount).to.equal(0);
+    expect(campaignState.rewardsClaimed).to.equal(0);
+
+    reviewerState = await program.account.reviewerAccount.fetch(reviewer1Account);
+    expect(reviewerState.approvedCount).to.equal(approvedCount - 1);
+
+    // What vested before the clawback is paid in full, without re-applying the schedule to the cut total
+    balanceBefore = await connection.getBalance(reviewer1.publicKey);
+    await withdrawVested(vestingCampaign, reviewer1);
+
+    review = await program.account.reviewAccount.fetch(vestingReview);
+    campaignState = await program.account.reviewCampaign.fetch(vestingCampaign);
+    expect(review.vestingWithdrawn.toNumber()).to.equal(keptReward);
+    expect(campaignState.vestingLocked.toNumber()).to.equal(0);
+    expect(await connection.getBalance(reviewer1.publicKey)).to.equal(balanceBefore + keptReward - firstWithdrawal);
+
+    try {
+      await withdrawVested(vestingCampaign, reviewer1);
+      expect.fail("Should have failed - everything kept was withdrawn");
+    } catch (error) {
+      expect(error.message).to.include("NothingVested");
+    }
+
+    try {
+      await claimSolReward(vestingCampaign, reviewer1);
+      expect.fail("Should have failed - the review was clawed back");
+    } catch (error) {
+      expect(error.message).to.include("ReviewNotApproved");
+    }
+  });
+
+  xit("Should allow all reviewers to claim rewards", async () => {
+
+    // Wait a bit for transactions to settle
+    await new Promise(resolve => setTimeout(resolve, 2000));
+    // All reviewers claim their rewards
+    await program.methods
+      .claimReward({ sol: {} })
+      .accountsPartial({
+        reviewer: reviewer1.publicKey,
+        reviewAccount: review1Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer1Account,
+        platform: platform,
+        sponsor: null,
+        revMint: null,
+        reviewerRevAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer1])
+      .rpc();
+
+    await program.methods
+      .claimReward({ sol: {} })
+      .accountsPartial({
+        reviewer: reviewer2.publicKey,
+        reviewAccount: review2Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer2Account,
+        platform: platform,
+        sponsor: null,
+        revMint: null,
+        reviewerRevAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer2])
+      .rpc();
+
+    // Reviewer 3 takes REV instead, the SOL share stays in the vault for the merchant
+    const reviewer3Rev = await getOrCreateAssociatedTokenAccount(connection, reviewer3, revMint, reviewer3.publicKey);
+    await program.methods
+      .claimReward({ rev: {} })
+      .accountsPartial({
+        reviewer: reviewer3.publicKey,
+        reviewAccount: review3Account,
+        campaign: campaign,
+        vault: campaignVault,
+        reviewerAccount: reviewer3Account,
+        platform: platform,
+        sponsor: null,
+        revMint: revMint,
+        reviewerRevAccount: reviewer3Rev.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reviewer3])
+      .rpc();
+
+    const campaignState = await program.account.reviewCampaign.fetch(campaign);
+    expect(campaignState.revPaidRewards.toNumber()).to.be.greaterThan(0);
+    const revBalance = await getAccount(connection, reviewer3Rev.address);
+    expect(Number(revBalance.amount)).to.equal(Math.floor(campaignState.revPaidRewards.toNumber() * (10000 + REV_PREMIUM_BPS) / 10000));
+    const reviewer3State = await program.account.reviewerAccount.fetch(reviewer3Account);
+    expect(reviewer3State.totalEarned.toNumber()).to.equal(campaignState.revPaidRewards.toNumber());
+
+    console.log("All reviewers claimed rewards successfully");
+
+    // Verify all rewards were claimed
+    const review1 = await program.account.reviewAccount.fetch(review1Account);
+    const review2 = await program.account.reviewAccount.fetch(review2Account);
+    const review3 = await program.account.reviewAccount.fetch(review3Account);
+
+    expect(review1.rewardClaimed).to.be.true;
+    expect(review2.rewardClaimed).to.be.true;
+    expect(review3.rewardClaimed).to.be.true;
+  });
+
+  it("Review Replies, Should let the merchant reply, edit and delete while the admin hides", async () => {
+    const reply = PublicKey.findProgramAddressSync(
+      [Buffer.from("reply"), review1Account.toBuffer()],
+      program.programId
+    )[0];
+
+    // A reply filed under the merchant's own platform would only be moderatable by the merchant
+    try {
+      await program.methods
+        .replyToReview("Thanks! A simpler marketplace UI ships next month.")
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          reply: reply,
+          reviewAccount: review1Account,
+          campaign: campaign,
+          platform: merchantPlatform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - the campaign was created under another platform");
+    } catch (error) {
+      expect(error.message).to.include("CampaignPlatformMismatch");
+    }
+
+    await program.methods
+      .replyToReview("Thanks! A simpler marketplace UI ships next month.")
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        reply: reply,
+        reviewAccount: review1Account,
+        campaign: campaign,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    await program.methods
+      .editReply("Thanks! The simpler marketplace UI is live now.")
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        reply: reply,
+      })
+      .signers([merchant])
+      .rpc();
+
+    await program.methods
+      .moderateReply(true)
+      .accountsPartial({
+        admin: admin.publicKey,
+        reply: reply,
+        platform: platform,
+      })
+      .signers([admin])
+      .rpc();
+
+    const replyAccount = await program.account.reviewReply.fetch(reply);
+    expect(replyAccount.body).to.equal("Thanks! The simpler marketplace UI is live now.");
+    expect(replyAccount.hidden).to.be.true;
+    expect(replyAccount.review.toBase58()).to.equal(review1Account.toBase58());
+    expect(replyAccount.platform.toBase58()).to.equal(platform.toBase58());
+
+    // Only the merchant can reply
+    try {
+      await program.methods
+        .replyToReview("Not my campaign")
+        .accountsPartial({
+          merchant: reviewer2.publicKey,
+          reply: PublicKey.findProgramAddressSync(
+            [Buffer.from("reply"), review2Account.toBuffer()],
+            program.programId
+          )[0],
+          reviewAccount: review2Account,
+          campaign: campaign,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reviewer2])
+        .rpc();
+
+      expect.fail("Should have failed - reviewer is not the campaign merchant");
+    } catch (error) {
+      expect(error.message).to.include("ConstraintHasOne");
+    }
+
+    try {
+      await program.methods
+        .editReply("x".repeat(281))
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          reply: reply,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - reply too long");
+    } catch (error) {
+      expect(error.message).to.include("InvalidReplyLength");
+    }
+
+    await program.methods
+      .closeReply()
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        reply: reply,
+      })
+      .signers([merchant])
+      .rpc();
+
+    expect(await connection.getAccountInfo(reply)).to.be.null;
   });
 
   it(" Admin Function, Should claim platform fees successfully", async () => {
     const adminBalanceBefore = await provider.connection.getBalance(admin.publicKey);
     const treasuryBalanceBefore = await provider.connection.getBalance(treasury);
+    const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(0);
+    const withdrawable = treasuryBalanceBefore - rentExempt;
+
+    if (withdrawable > 0) {
+      const platformBefore = await program.account.platformConfig.fetch(platform);
 
-    if (treasuryBalanceBefore > 0) {
       const tx = await program.methods
-        .claimFee()
+        .claimFee(new BN(withdrawable))
         .accountsPartial({
           admin: admin.publicKey,
           platform: platform,
           treasury: treasury,
+          destination: admin.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([admin])
@@ -403,24 +2075,126 @@ describe("realvue", () => {
       const adminBalanceAfter = await provider.connection.getBalance(admin.publicKey);
       expect(adminBalanceAfter).to.be.greaterThan(adminBalanceBefore);
 
-      // Verify treasury is empty
+      // Verify treasury kept its rent-exempt floor
       const treasuryBalanceAfter = await provider.connection.getBalance(treasury);
-      expect(treasuryBalanceAfter).to.equal(0);
+      expect(treasuryBalanceAfter).to.equal(rentExempt);
 
-      // Verify platform stats updated
+      // Withdrawals are tracked separately from collected fees
       const platformAccount = await program.account.platformConfig.fetch(platform);
-      expect(platformAccount.totalFeesCollected.toNumber()).to.be.greaterThan(0);
+      expect(platformAccount.totalFeesCollected.toString()).to.equal(platformBefore.totalFeesCollected.toString());
+      expect(platformAccount.totalFeesWithdrawn.toNumber()).to.equal(withdrawable);
+    }
+  });
+
+  it("Should fail to withdraw below the treasury rent-exempt floor", async () => {
+    try {
+      await program.methods
+        .claimFee(new BN(1))
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          treasury: treasury,
+          destination: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - treasury at rent-exempt floor");
+    } catch (error) {
+      expect(error.message).to.include("InsufficientTreasuryFunds");
+    }
+  });
+
+  it("Should require a request when withdrawals are timelocked", async () => {
+    await program.methods
+      .setWithdrawalTimelock(new BN(2))
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .claimFee(new BN(1))
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          treasury: treasury,
+          destination: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - withdrawals are timelocked");
+    } catch (error) {
+      expect(error.message).to.include("WithdrawalTimelocked");
+    }
+
+    // Lowering the delay is queued behind the current one
+    await program.methods
+      .setWithdrawalTimelock(new BN(0))
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    let platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.withdrawalTimelock.toNumber()).to.equal(2);
+    expect(platformAccount.pendingTimelock.timelock.toNumber()).to.equal(0);
+
+    try {
+      await program.methods
+        .claimFee(new BN(1))
+        .accountsPartial({
+          admin: admin.publicKey,
+          platform: platform,
+          treasury: treasury,
+          destination: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Should have failed - the decrease is still queued");
+    } catch (error) {
+      expect(error.message).to.include("WithdrawalTimelocked");
     }
+
+    // Once the old delay has passed the decrease applies on the next use
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await program.methods
+      .setWithdrawalTimelock(new BN(0))
+      .accountsPartial({
+        admin: admin.publicKey,
+        platform: platform,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    platformAccount = await program.account.platformConfig.fetch(platform);
+    expect(platformAccount.withdrawalTimelock.toNumber()).to.equal(0);
+    expect(platformAccount.pendingTimelock).to.be.null;
   });
 
   xit("Should fail when non-admin tries to claim fees", async () => {
     try {
       await program.methods
-        .claimFee()
+        .claimFee(new BN(1))
         .accountsPartial({
           admin: merchant.publicKey, // Wrong admin
           platform: platform,
           treasury: treasury,
+          destination: merchant.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([merchant])
@@ -432,12 +2206,59 @@ describe("realvue", () => {
     }
   });
 
+  it("Should fail to close a campaign with unclaimed rewards", async () => {
+    try {
+      await program.methods
+        .closeCampaign()
+        .accountsPartial({
+          merchant: merchant.publicKey,
+          campaign: campaign,
+          platform: platform,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+
+      expect.fail("Should have failed - approved reviews haven't claimed");
+    } catch (error) {
+      expect(error.message).to.include("RewardsUnclaimed");
+    }
+  });
+
   it("Should close campaign successfully", async () => {
+    // A campaign nobody reviewed yet has nothing left to settle
+    const closingCampaign = await nextCampaign();
+    const closingVault = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_vault"), closingCampaign.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .createCampaign("Closing Campaign", PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
+      .accountsPartial({
+        merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
+        campaign: closingCampaign,
+        merchantAccount: merchantAccount,
+        merchantAta: merchantAta,
+        platform: platform,
+        revMint: revMint,
+        vault: closingVault,
+        treasury: treasury,
+        category: category,
+        referrerAccount: null,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
     const tx = await program.methods
       .closeCampaign()
       .accountsPartial({
         merchant: merchant.publicKey,
-        campaign: campaign,
+        campaign: closingCampaign,
         platform: platform,
         systemProgram: SystemProgram.programId,
       })
@@ -448,9 +2269,9 @@ describe("realvue", () => {
 
     // Check if the account still exists before trying to fetch it
     try {
-      const campaignAccountInfo = await provider.connection.getAccountInfo(campaign);
+      const campaignAccountInfo = await provider.connection.getAccountInfo(closingCampaign);
       if (campaignAccountInfo) {
-        const campaignAccount = await program.account.reviewCampaign.fetch(campaign);
+        const campaignAccount = await program.account.reviewCampaign.fetch(closingCampaign);
         expect(campaignAccount.active).to.be.false;
       } else {
         console.log("Campaign account was closed (no longer exists)");
@@ -486,15 +2307,19 @@ xit("Should refund deposit and close campaign successfully", async () => {
 
   xit("Should fail to refund active campaign", async () => {
     await program.methods
-      .createCampaign(CAMPAIGN_NAME, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED)
+      .createCampaign(CAMPAIGN_NAME, PRODUCT_ID, DEPOSIT_AMOUNT, START_TIME, END_TIME, REVIEWS_NEEDED, null)
       .accountsPartial({
         merchant: merchant.publicKey,
+        merchantCounter: merchantCounter,
         campaign: campaign,
+        merchantAccount: merchantAccount,
         merchantAta: merchantAta,
         platform: platform,
         revMint: revMint,
         vault: campaignVault,
         treasury: treasury,
+        category: category,
+        referrerAccount: null,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -525,7 +2350,7 @@ xit("Should refund deposit and close campaign successfully", async () => {
     // Close reviewer accounts if they exist
     for (const reviewer of [reviewer1, reviewer2, reviewer3]) {
       const reviewerAccount = PublicKey.findProgramAddressSync(
-        [Buffer.from("reviewer"), reviewer.publicKey.toBuffer()],
+        [Buffer.from("reviewer"), platform.toBuffer(), reviewer.publicKey.toBuffer()],
         program.programId
       )[0];
       try {
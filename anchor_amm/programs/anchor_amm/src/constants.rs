@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
 
 #[constant]
-pub const SEED: &str = "anchor";
\ No newline at end of file
+pub const SEED: &str = "anchor";
+
+// minimum seconds between two oracle observations, the ring buffer covers at least this times its capacity
+pub const OBSERVATION_INTERVAL: i64 = 60;
+
+// accounts route_swap expects per pool: config, oracle, mint_x, mint_y, vault_x, vault_y, user_x, user_y, token_program_x, token_program_y
+pub const ROUTE_ACCOUNTS_PER_HOP: usize = 10;
\ No newline at end of file
@@ -41,6 +41,12 @@ pub enum AmmError {
     InsufficientBalance,
     #[msg("Zero balance.")]
     ZeroBalance,
+    #[msg("Mint has an unsupported token extension.")]
+    UnsupportedMintExtension,
+    #[msg("Not enough price history for the requested window.")]
+    InsufficientOracleHistory,
+    #[msg("Route pools don't connect the input and output mints.")]
+    InvalidRoute,
 }
 
 impl From<CurveError> for AmmError {
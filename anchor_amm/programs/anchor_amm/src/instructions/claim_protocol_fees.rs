@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}};
+
+use crate::{error::AmmError, Config};
+
+#[derive(Accounts)]
+pub struct ClaimProtocolFees<'info> {
+    pub authority: Signer<'info>, // the pool update authority stored on the config
+    #[account(mint::token_program = token_program_x)]
+    pub mint_x: InterfaceAccount<'info, Mint>, // token x
+    #[account(mint::token_program = token_program_y)]
+    pub mint_y: InterfaceAccount<'info, Mint>, // token y
+
+    #[account(
+        mut, // mutable because the accrued fees are reset
+        has_one= mint_x,
+        has_one= mint_y,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+        constraint = config.authority.is_some() @ AmmError::NoAuthoritySet,
+        constraint = config.authority == Some(authority.key()) @ AmmError::InvalidAuthority,
+    )]
+    pub config: Account<'info, Config>, //the escrow service / swap service / liquidity pool
+
+    #[account(
+        mut, // mutable because lamports change
+        associated_token::mint = mint_x,
+        associated_token::authority = config,
+        associated_token::token_program = token_program_x,
+    )]
+    pub vault_x: InterfaceAccount<'info, TokenAccount>, // mint_x associated token account
+
+    #[account(
+        mut, // mutable because lamports change
+        associated_token::mint = mint_y,
+        associated_token::authority = config,
+        associated_token::token_program = token_program_y,
+    )]
+    pub vault_y: InterfaceAccount<'info, TokenAccount>, // mint_y associated token account
+
+    #[account(
+        mut,
+        token::mint = mint_x,
+        token::token_program = token_program_x,
+    )]
+    pub destination_x: InterfaceAccount<'info, TokenAccount>, // any x token account chosen by the authority
+
+    #[account(
+        mut,
+        token::mint = mint_y,
+        token::token_program = token_program_y,
+    )]
+    pub destination_y: InterfaceAccount<'info, TokenAccount>, // any y token account chosen by the authority
+
+    pub token_program_x: Interface<'info, TokenInterface>, //token program that owns mint_x, spl token or token-2022
+    pub token_program_y: Interface<'info, TokenInterface>, //token program that owns mint_y, spl token or token-2022
+}
+
+impl<'info> ClaimProtocolFees<'info> {
+    pub fn claim_protocol_fees(&mut self) -> Result<()> {
+        let amount_x = self.config.protocol_fees_x;
+        let amount_y = self.config.protocol_fees_y;
+        require!(amount_x > 0 || amount_y > 0, AmmError::ZeroBalance);
+
+        // reset before paying out so the reserves never count the claimed fees twice
+        self.config.protocol_fees_x = 0;
+        self.config.protocol_fees_y = 0;
+
+        if amount_x > 0 {
+            self.transfer_protocol_fees(true, amount_x)?;
+        }
+        if amount_y > 0 {
+            self.transfer_protocol_fees(false, amount_y)?;
+        }
+
+        emit!(ProtocolFeesClaimEvent {
+            config: self.config.key(),
+            authority: self.authority.key(),
+            destination_x: self.destination_x.key(),
+            destination_y: self.destination_y.key(),
+            amount_x,
+            amount_y,
+        });
+        Ok(())
+    }
+
+    fn transfer_protocol_fees(&self, is_x: bool, amount: u64) -> Result<()> {
+        let (from, to, mint, cpi_program) = match is_x {
+            true => (self.vault_x.to_account_info(), self.destination_x.to_account_info(), &self.mint_x, self.token_program_x.to_account_info()),
+            false => (self.vault_y.to_account_info(), self.destination_y.to_account_info(), &self.mint_y, self.token_program_y.to_account_info()),
+        };
+
+        let cpi_accounts = TransferChecked {
+            from,
+            mint: mint.to_account_info(),
+            to,
+            authority: self.config.to_account_info(),
+        };
+
+        let seeds = &[
+            &b"config"[..],
+            &self.config.seed.to_le_bytes(),
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_checked(ctx, amount, mint.decimals)
+    }
+}
+
+// Event for tracking protocol fee claims
+#[event]
+pub struct ProtocolFeesClaimEvent {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub destination_x: Pubkey,
+    pub destination_y: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
\ No newline at end of file
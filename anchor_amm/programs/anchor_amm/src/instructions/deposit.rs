@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Transfer, transfer, Mint, Token, TokenAccount, MintTo, mint_to}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{transfer_checked, mint_to, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked}};
 use constant_product_curve::ConstantProduct;
 
-use crate::{error::AmmError, Config};
+use crate::{error::AmmError, Oracle, utils::pre_fee_amount, Config};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(mut)]
     pub user: Signer<'info>, // the liqidity provider that wants to deposit two different tokens into the liquidity pool
-    pub mint_x: Account<'info, Mint>, // token x
-    pub mint_y: Account<'info, Mint>, // token y
+    #[account(mint::token_program = token_program_x)]
+    pub mint_x: InterfaceAccount<'info, Mint>, // token x
+    #[account(mint::token_program = token_program_y)]
+    pub mint_y: InterfaceAccount<'info, Mint>, // token y
 
     #[account(
         has_one= mint_x,
@@ -25,46 +27,61 @@ pub struct Deposit<'info> {
         bump= config.lp_bump,
         mint::decimals = 6,
         mint::authority = config,
+        mint::token_program = token_program,
     )]
-    pub mint_lp: Account<'info, Mint>, // the liquidity provider token
+    pub mint_lp: InterfaceAccount<'info, Mint>, // the liquidity provider token
+
+    #[account(
+        mut, // mutable because the price accumulators change
+        seeds = [b"oracle", config.key().as_ref()],
+        bump = oracle.bump,
+    )]
+    pub oracle: Box<Account<'info, Oracle>>, // price accumulators and observations for this pool
 
     #[account(
         mut, // mutable because lamports change
         associated_token::mint = mint_x,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_x,
     )]
-    pub vault_x: Account<'info, TokenAccount>, // mint_x associated token account
+    pub vault_x: InterfaceAccount<'info, TokenAccount>, // mint_x associated token account
 
     #[account(
         mut, // mutable because lamports change
         associated_token::mint = mint_y,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_y,
     )]
-    pub vault_y: Account<'info, TokenAccount>, // mint_y associated token account
+    pub vault_y: InterfaceAccount<'info, TokenAccount>, // mint_y associated token account
 
     #[account(
         mut, 
         associated_token::mint = mint_x,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program_x,
     )]
-    pub user_x: Account<'info, TokenAccount>, // liquidity provider's associated token account for x token
+    pub user_x: InterfaceAccount<'info, TokenAccount>, // liquidity provider's associated token account for x token
 
     #[account(
         mut,
         associated_token::mint = mint_y,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program_y,
     )]
-    pub user_y: Account<'info, TokenAccount>, // liquidity provider's associated token account for y token
+    pub user_y: InterfaceAccount<'info, TokenAccount>, // liquidity provider's associated token account for y token
 
     #[account(
         init_if_needed, // we dont know if this is a new liquidity provider so create ata if one does not exist
         payer = user,
         associated_token::mint = mint_lp,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_lp: Account<'info, TokenAccount>, //liquidity provider's associated token account for their lp token requested
+    pub user_lp: InterfaceAccount<'info, TokenAccount>, //liquidity provider's associated token account for their lp token requested
 
-    pub token_program: Program<'info, Token>, //token program      
+    pub token_program: Interface<'info, TokenInterface>, //token program for the lp mint
+    pub token_program_x: Interface<'info, TokenInterface>, //token program that owns mint_x, spl token or token-2022
+    pub token_program_y: Interface<'info, TokenInterface>, //token program that owns mint_y, spl token or token-2022
     pub associated_token_program: Program<'info, AssociatedToken>, // associated token program
     pub system_program: Program<'info, System>, // system program, we must always call this because it helps transfer SOL
 }
@@ -74,21 +91,26 @@ impl<'info> Deposit<'info>  {
         require!(self.config.locked == false, AmmError::PoolLocked); // check if pool is locked
         require!(amount != 0, AmmError::InvalidAmount); // lp amount requested must not be zero 
 
-        let (x, y) = match self.mint_lp.supply == 0 && self.vault_x.amount == 0 && self.vault_y.amount == 0 {
+        let (reserve_x, reserve_y) = self.config.reserves(self.vault_x.amount, self.vault_y.amount)?; // unclaimed protocol fees don't belong to lps
+
+        let (x, y) = match self.mint_lp.supply == 0 && reserve_x == 0 && reserve_y == 0 {
             true => (max_x, max_y), // if the pool is then this is our constant product curve
             false => {
                 let amount = ConstantProduct::xy_deposit_amounts_from_l(
-                    self.vault_x.amount, 
-                    self.vault_y.amount, 
+                    reserve_x, 
+                    reserve_y, 
                     self.mint_lp.supply, 
                     amount, 
                 6).unwrap();
-                (amount.x, amount.y)
+                // gross up so the vaults receive the curve amounts after any token-2022 transfer fee
+                (pre_fee_amount(&self.mint_x, amount.x)?, pre_fee_amount(&self.mint_y, amount.y)?)
             }
         };
 
         require!(x<=max_x && y<= max_y, AmmError::SlippageExceeded); // check for slippage
 
+        self.oracle.update(reserve_x, reserve_y, Clock::get()?.unix_timestamp); // accumulate the price before the reserves move
+
         self.deposit_tokens( true, x)?;
         self.deposit_tokens(false, y)?;
 
@@ -96,22 +118,21 @@ impl<'info> Deposit<'info>  {
     }
 
     pub fn deposit_tokens(&self, is_x: bool, amount: u64, ) -> Result<()>{ //is_x bool is used incase user want to deposit x or y token if it is not x token, deposit y
-        let (from, to) = match is_x {
-            true => (self.user_x.to_account_info(), self.vault_x.to_account_info()), // deposit from liquidity provider's x_ata to vault_x
-            false => (self.user_y.to_account_info(), self.vault_y.to_account_info()), // deposit from liquidity provider's y_ata to vault_y
+        let (from, to, mint, cpi_program) = match is_x {
+            true => (self.user_x.to_account_info(), self.vault_x.to_account_info(), &self.mint_x, self.token_program_x.to_account_info()), // deposit from liquidity provider's x_ata to vault_x
+            false => (self.user_y.to_account_info(), self.vault_y.to_account_info(), &self.mint_y, self.token_program_y.to_account_info()), // deposit from liquidity provider's y_ata to vault_y
         };
 
-        let cpi_program = self.token_program.to_account_info(); // invoke the token program 
-
-        let cpi_accounts = Transfer{
+        let cpi_accounts = TransferChecked{
             from,
+            mint: mint.to_account_info(),
             to,
             authority: self.user.to_account_info()
         };
 
         let ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-        transfer(ctx, amount)
+        transfer_checked(ctx, amount, mint.decimals)
     }
 
     // we need to mint lp token based on the amount the lp requested for
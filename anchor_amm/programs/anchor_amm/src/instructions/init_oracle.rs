@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, Oracle};
+
+#[derive(Accounts)]
+pub struct InitOracle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>, // anyone can pay, the oracle starts from the same state whoever creates it
+
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>, //the escrow service / swap service / liquidity pool
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"oracle", config.key().as_ref()],
+        bump,
+        space = 8 + Oracle::INIT_SPACE,
+    )]
+    pub oracle: Box<Account<'info, Oracle>>, // price accumulators and observations for this pool
+
+    pub system_program: Program<'info, System>, // system program, we must always call this because it helps transfer SOL
+}
+
+impl<'info> InitOracle<'info> {
+    // pools created before the oracle existed need one before they can deposit, swap or withdraw again
+    pub fn init_oracle(&mut self, bumps: InitOracleBumps) -> Result<()> {
+        let config = self.config.key();
+        self.oracle.init(config, Clock::get()?.unix_timestamp, bumps.oracle); // history starts now, accumulators start at zero
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
-use crate::Config;
+use crate::{error::AmmError, utils::validate_mint_extensions, Config, Oracle};
 
 #[derive(Accounts)]
 #[instruction(seed: u64)]
 pub struct Initialize<'info>{
     #[account(mut)]
     pub initializer: Signer<'info>,
-    pub mint_x: Account<'info, Mint>, // x token
-    pub mint_y: Account<'info, Mint>,// y token
+    #[account(mint::token_program = token_program_x)]
+    pub mint_x: InterfaceAccount<'info, Mint>, // x token
+    #[account(mint::token_program = token_program_y)]
+    pub mint_y: InterfaceAccount<'info, Mint>,// y token
 
     #[account(
         init,
@@ -18,8 +20,9 @@ pub struct Initialize<'info>{
         bump,
         mint::decimals = 6,
         mint::authority = config,
+        mint::token_program = token_program,
     )]
-    pub mint_lp: Account<'info, Mint>, // liquidity provider token
+    pub mint_lp: InterfaceAccount<'info, Mint>, // liquidity provider token
 
     #[account(
         init,
@@ -30,29 +33,46 @@ pub struct Initialize<'info>{
     )]
     pub config: Account<'info, Config>, // the escrow service / swap service / liquidity pool
 
+    #[account(
+        init,
+        payer = initializer,
+        seeds = [b"oracle", config.key().as_ref()],
+        bump,
+        space = 8 + Oracle::INIT_SPACE,
+    )]
+    pub oracle: Box<Account<'info, Oracle>>, // price accumulators and observations for this pool
+
     #[account(
         init,
         payer = initializer,
         associated_token::mint = mint_x,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_x,
     )]
-    pub vault_x: Account<'info, TokenAccount>, // mint_x associated token account
+    pub vault_x: InterfaceAccount<'info, TokenAccount>, // mint_x associated token account
 
     #[account(
         init,
         payer = initializer,
         associated_token::mint = mint_y,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_y,
     )]
-    pub vault_y: Account<'info, TokenAccount>, // mint_y associated token account
+    pub vault_y: InterfaceAccount<'info, TokenAccount>, // mint_y associated token account
 
-    pub token_program: Program<'info, Token>, //token program- 
+    pub token_program: Interface<'info, TokenInterface>, //token program for the lp mint
+    pub token_program_x: Interface<'info, TokenInterface>, //token program that owns mint_x, spl token or token-2022
+    pub token_program_y: Interface<'info, TokenInterface>, //token program that owns mint_y, spl token or token-2022
     pub associated_token_program: Program<'info, AssociatedToken>, // associated token program
     pub system_program: Program<'info, System>, // system program, we must always call this because it helps transfer SOL
 }
 
 impl<'info> Initialize<'info>{
     pub fn initialize(&mut self, seed: u64, fee: u16, authority: Option<Pubkey>, bumps: InitializeBumps ) -> Result<()>{
+        require!(fee <= 10_000, AmmError::InvalidFee); // fee is in basis points, 10_000 = 100%
+        validate_mint_extensions(&self.mint_x)?; // reject token-2022 mints that can move or freeze vault funds
+        validate_mint_extensions(&self.mint_y)?;
+
         self.config.set_inner(Config { //.set_inner helps auto-fill the parameters without missing one
              seed, 
              authority, 
@@ -61,8 +81,14 @@ impl<'info> Initialize<'info>{
              fee, 
              locked: false, 
              config_bump: bumps.config, 
-             lp_bump: bumps.mint_lp  
+             lp_bump: bumps.mint_lp,
+             protocol_fee: 0, // set later by the authority with update_protocol_fee
+             protocol_fees_x: 0,
+             protocol_fees_y: 0,
             });
+
+        let config = self.config.key();
+        self.oracle.init(config, Clock::get()?.unix_timestamp, bumps.oracle); // first observation, accumulators start at zero
         Ok(())
     }
 }
\ No newline at end of file
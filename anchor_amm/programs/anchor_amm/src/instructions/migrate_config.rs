@@ -0,0 +1,65 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}, Discriminator};
+
+use crate::Config;
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>, // anyone can pay for the extra rent, the migration only adds zeroed fields
+
+    /// CHECK: a pool created before the protocol fee fields were added is too short to deserialize
+    /// as a Config, so it is checked by hand against the seeds, owner and discriminator
+    #[account(
+        mut, // mutable because the account grows
+        seeds = [b"config", seed.to_le_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>, //the escrow service / swap service / liquidity pool
+
+    pub system_program: Program<'info, System>, // system program, pays the rent top up
+}
+
+impl<'info> MigrateConfig<'info> {
+    // grows an old config to the current layout, the appended protocol_fee and protocol_fees_x/y read back as zero
+    pub fn migrate_config(&mut self) -> Result<()> {
+        let config = self.config.to_account_info();
+        require!(
+            config.try_borrow_data()?.starts_with(Config::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let new_len = 8 + Config::INIT_SPACE;
+        if config.data_len() >= new_len {
+            return Ok(()); // created or migrated with the current layout already
+        }
+
+        let rent_exempt = Rent::get()?.minimum_balance(new_len);
+        let top_up = rent_exempt.saturating_sub(config.lamports());
+        if top_up > 0 {
+            let cpi_accounts = Transfer {
+                from: self.payer.to_account_info(),
+                to: config.clone(),
+            };
+
+            let ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+            transfer(ctx, top_up)?;
+        }
+
+        config.realloc(new_len, true)?; // zero the new bytes, no protocol fee until the authority sets one
+
+        emit!(ConfigMigrateEvent {
+            config: config.key(),
+            payer: self.payer.key(),
+        });
+        Ok(())
+    }
+}
+
+// Event for tracking pool migrations
+#[event]
+pub struct ConfigMigrateEvent {
+    pub config: Pubkey,
+    pub payer: Pubkey,
+}
\ No newline at end of file
@@ -2,8 +2,20 @@ pub mod initialize;
 pub mod deposit;
 pub mod swap;
 pub mod withdraw;
+pub mod update;
+pub mod claim_protocol_fees;
+pub mod observe;
+pub mod route_swap;
+pub mod migrate_config;
+pub mod init_oracle;
 
 pub use initialize::*;
 pub use deposit::*;
 pub use swap::*;
-pub use withdraw::*;
\ No newline at end of file
+pub use withdraw::*;
+pub use update::*;
+pub use claim_protocol_fees::*;
+pub use observe::*;
+pub use route_swap::*;
+pub use migrate_config::*;
+pub use init_oracle::*;
\ No newline at end of file
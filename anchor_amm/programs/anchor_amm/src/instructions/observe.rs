@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{Config, Oracle, Twap};
+
+#[derive(Accounts)]
+pub struct Observe<'info> {
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>, //the escrow service / swap service / liquidity pool
+
+    #[account(
+        seeds = [b"oracle", config.key().as_ref()],
+        bump = oracle.bump,
+    )]
+    pub oracle: Box<Account<'info, Oracle>>, // price accumulators and observations for this pool
+
+    #[account(
+        associated_token::mint = config.mint_x,
+        associated_token::authority = config,
+        associated_token::token_program = token_program_x,
+    )]
+    pub vault_x: InterfaceAccount<'info, TokenAccount>, // mint_x associated token account
+
+    #[account(
+        associated_token::mint = config.mint_y,
+        associated_token::authority = config,
+        associated_token::token_program = token_program_y,
+    )]
+    pub vault_y: InterfaceAccount<'info, TokenAccount>, // mint_y associated token account
+
+    pub token_program_x: Interface<'info, TokenInterface>, //token program that owns mint_x, spl token or token-2022
+    pub token_program_y: Interface<'info, TokenInterface>, //token program that owns mint_y, spl token or token-2022
+}
+
+impl<'info> Observe<'info> {
+    // read only, other programs get the twap back through return data
+    pub fn observe(&self, window: u32) -> Result<Twap> {
+        let (reserve_x, reserve_y) = self.config.reserves(self.vault_x.amount, self.vault_y.amount)?;
+        self.oracle.observe(reserve_x, reserve_y, Clock::get()?.unix_timestamp, window)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::{get_associated_token_address_with_program_id, AssociatedToken}, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{error::AmmError, Config, Oracle, Swap, ROUTE_ACCOUNTS_PER_HOP};
+
+#[derive(Accounts)]
+pub struct RouteSwap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>, // the user swapping through every pool in the route
+    pub mint_in: InterfaceAccount<'info, Mint>, // token the first pool receives
+    pub mint_out: InterfaceAccount<'info, Mint>, // token the last pool pays out
+
+    pub associated_token_program: Program<'info, AssociatedToken>, // associated token program
+    pub system_program: Program<'info, System>, // system program, we must always call this because it helps transfer SOL
+    // remaining accounts: ROUTE_ACCOUNTS_PER_HOP accounts for every pool, in route order
+}
+
+impl<'info> RouteSwap<'info> {
+    pub fn route_swap(
+        &mut self,
+        amount_in: u64,      // amount of mint_in the user sends to the first pool
+        min_amount_out: u64, // minimum amount of mint_out the user expects at the end of the route (slippage protection)
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount_in > 0, AmmError::InvalidAmount);
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.chunks_exact(ROUTE_ACCOUNTS_PER_HOP).remainder().is_empty(),
+            AmmError::InvalidRoute
+        );
+
+        let mut current_mint = self.mint_in.key();
+        let mut amount = amount_in;
+        let hops = remaining_accounts.chunks(ROUTE_ACCOUNTS_PER_HOP);
+        let hop_count = u8::try_from(hops.len()).map_err(|_| AmmError::InvalidRoute)?;
+
+        for hop in hops {
+            let mut pool = self.load_pool(hop)?;
+
+            // the pool must trade the token the previous hop paid out
+            let swap_x = match current_mint {
+                mint if mint == pool.config.mint_x => true,
+                mint if mint == pool.config.mint_y => false,
+                _ => return err!(AmmError::InvalidRoute),
+            };
+            current_mint = if swap_x { pool.config.mint_y } else { pool.config.mint_x };
+
+            // each hop respects the pool lock and fee, slippage is only enforced on the final amount
+            amount = pool.swap(swap_x, amount, 0)?;
+
+            // persist the pool state before a later hop reloads the same pool
+            pool.config.exit(&crate::ID)?;
+            pool.oracle.exit(&crate::ID)?;
+        }
+
+        require_keys_eq!(current_mint, self.mint_out.key(), AmmError::InvalidRoute);
+        require!(amount >= min_amount_out, AmmError::SlippageExceeded);
+
+        // Emit event for tracking
+        emit!(RouteSwapEvent {
+            user: self.user.key(),
+            mint_in: self.mint_in.key(),
+            mint_out: self.mint_out.key(),
+            amount_in,
+            amount_out: amount,
+            hops: hop_count,
+        });
+        Ok(())
+    }
+
+    // remaining accounts aren't checked by anchor, so apply the same checks as the Swap accounts
+    fn load_pool(&self, hop: &'info [AccountInfo<'info>]) -> Result<Swap<'info>> {
+        let config = Account::<Config>::try_from(&hop[0])?; // owner and discriminator make it one of our pools
+        let oracle = Account::<Oracle>::try_from(&hop[1])?;
+        require_keys_eq!(oracle.config, config.key(), AmmError::InvalidRoute);
+
+        let mint_x = InterfaceAccount::<Mint>::try_from(&hop[2])?;
+        let mint_y = InterfaceAccount::<Mint>::try_from(&hop[3])?;
+        require_keys_eq!(mint_x.key(), config.mint_x, AmmError::InvalidToken);
+        require_keys_eq!(mint_y.key(), config.mint_y, AmmError::InvalidToken);
+
+        let token_program_x = Interface::<TokenInterface>::try_from(&hop[8])?;
+        let token_program_y = Interface::<TokenInterface>::try_from(&hop[9])?;
+        require_keys_eq!(*hop[2].owner, token_program_x.key(), AmmError::InvalidToken);
+        require_keys_eq!(*hop[3].owner, token_program_y.key(), AmmError::InvalidToken);
+
+        let vault_x = InterfaceAccount::<TokenAccount>::try_from(&hop[4])?;
+        let vault_y = InterfaceAccount::<TokenAccount>::try_from(&hop[5])?;
+        require_keys_eq!(
+            vault_x.key(),
+            get_associated_token_address_with_program_id(&config.key(), &mint_x.key(), &token_program_x.key()),
+            AmmError::InvalidToken
+        );
+        require_keys_eq!(
+            vault_y.key(),
+            get_associated_token_address_with_program_id(&config.key(), &mint_y.key(), &token_program_y.key()),
+            AmmError::InvalidToken
+        );
+
+        let user_x = InterfaceAccount::<TokenAccount>::try_from(&hop[6])?;
+        let user_y = InterfaceAccount::<TokenAccount>::try_from(&hop[7])?;
+        require!(user_x.mint == mint_x.key() && user_x.owner == self.user.key(), AmmError::InvalidToken);
+        require!(user_y.mint == mint_y.key() && user_y.owner == self.user.key(), AmmError::InvalidToken);
+        require!(hop[0].is_writable && hop[1].is_writable, AmmError::InvalidRoute);
+
+        Ok(Swap {
+            user: self.user.clone(),
+            mint_x,
+            mint_y,
+            config,
+            oracle: Box::new(oracle),
+            vault_x,
+            vault_y,
+            user_x,
+            user_y,
+            token_program_x,
+            token_program_y,
+            associated_token_program: self.associated_token_program.clone(),
+            system_program: self.system_program.clone(),
+        })
+    }
+}
+
+// Event for tracking routed swaps, every hop also emits its own SwapEvent
+#[event]
+pub struct RouteSwapEvent {
+    pub user: Pubkey,
+    pub mint_in: Pubkey,
+    pub mint_out: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub hops: u8,
+}
\ No newline at end of file
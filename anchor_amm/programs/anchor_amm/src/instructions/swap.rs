@@ -1,17 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{ transfer, Mint, Token, TokenAccount, Transfer}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{ transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}};
 use constant_product_curve::{ConstantProduct, LiquidityPair};
 
-use crate::{error::AmmError, Config};
+use crate::{error::AmmError, Oracle, utils::{post_fee_amount, pre_fee_amount}, Config};
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(mut)]
     pub user: Signer<'info>, // the liqidity provider that wants to deposit two different tokens into the liquidity pool
-    pub mint_x: Account<'info, Mint>, // token x
-    pub mint_y: Account<'info, Mint>, // token y
+    #[account(mint::token_program = token_program_x)]
+    pub mint_x: InterfaceAccount<'info, Mint>, // token x
+    #[account(mint::token_program = token_program_y)]
+    pub mint_y: InterfaceAccount<'info, Mint>, // token y
 
     #[account(
+        mut, // mutable because protocol fees accrue on every swap
         has_one= mint_x,
         has_one= mint_y,
         seeds = [b"config", config.seed.to_le_bytes().as_ref()],
@@ -19,36 +22,48 @@ pub struct Swap<'info> {
     )]
     pub config: Account<'info, Config>, //the escrow service / swap service / liquidity pool
 
+    #[account(
+        mut, // mutable because the price accumulators change
+        seeds = [b"oracle", config.key().as_ref()],
+        bump = oracle.bump,
+    )]
+    pub oracle: Box<Account<'info, Oracle>>, // price accumulators and observations for this pool
+
     #[account(
         mut, // mutable because lamports change
         associated_token::mint = mint_x,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_x,
     )]
-    pub vault_x: Account<'info, TokenAccount>, // mint_x associated token account
+    pub vault_x: InterfaceAccount<'info, TokenAccount>, // mint_x associated token account
 
     #[account(
         mut, // mutable because lamports change
         associated_token::mint = mint_y,
-        associated_token::authority = config
+        associated_token::authority = config,
+        associated_token::token_program = token_program_y,
     )]
-    pub vault_y: Account<'info, TokenAccount>, // mint_y associated token account
+    pub vault_y: InterfaceAccount<'info, TokenAccount>, // mint_y associated token account
 
     #[account(
         mut, 
         associated_token::mint = mint_x,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program_x,
     )]
-    pub user_x: Account<'info, TokenAccount>, // user's associated token account for x token for swap
+    pub user_x: InterfaceAccount<'info, TokenAccount>, // user's associated token account for x token for swap
 
     #[account(
         mut,
         associated_token::mint = mint_y,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program_y,
     )]
-    pub user_y: Account<'info, TokenAccount>, // user's associated token account for y token for swap
+    pub user_y: InterfaceAccount<'info, TokenAccount>, // user's associated token account for y token for swap
 
 
-    pub token_program: Program<'info, Token>, //token program
+    pub token_program_x: Interface<'info, TokenInterface>, //token program that owns mint_x, spl token or token-2022
+    pub token_program_y: Interface<'info, TokenInterface>, //token program that owns mint_y, spl token or token-2022
     pub associated_token_program: Program<'info, AssociatedToken>, // associated token program
     pub system_program: Program<'info, System>, // system program, we must always call this because it helps transfer SOL
 }
@@ -59,40 +74,49 @@ impl<'info> Swap<'info> {
         swap_x: bool,        // true = swap X for Y, false = swap Y for X
         amount_in: u64,      // amount of tokens user wants to swap
         min_amount_out: u64, // minimum amount user expects to receive (slippage protection)
-    ) -> Result<()> {
+    ) -> Result<u64> {
         // Safety checks
         require!(self.config.locked == false, AmmError::PoolLocked);
         require!(amount_in > 0, AmmError::InvalidAmount);
-        require!(self.vault_x.amount > 0 && self.vault_y.amount > 0, AmmError::NoLiquidityInPool);
+
+        // unclaimed protocol fees are excluded from the reserves used for pricing
+        let (reserve_x, reserve_y) = self.config.reserves(self.vault_x.amount, self.vault_y.amount)?;
+        require!(reserve_x > 0 && reserve_y > 0, AmmError::NoLiquidityInPool);
 
         let mut curve = ConstantProduct::init(
-            self.vault_x.amount, 
-            self.vault_y.amount, 
-            self.vault_x.amount, 
+            reserve_x, 
+            reserve_y, 
+            reserve_x, 
             self.config.fee, 
            None
         ).map_err(AmmError::from)?;
 
-        let p = match swap_x {
-            true => LiquidityPair::X,
-            false => LiquidityPair::Y
+        let (p, mint_in, mint_out) = match swap_x {
+            true => (LiquidityPair::X, &self.mint_x, &self.mint_y),
+            false => (LiquidityPair::Y, &self.mint_y, &self.mint_x)
         };
 
-   let res = curve.swap(p, amount_in, min_amount_out).map_err(AmmError::from)?;
+        // price the swap on what the vault actually receives after any token-2022 transfer fee
+        let amount_received = post_fee_amount(mint_in, amount_in)?;
+
+   let res = curve.swap(p, amount_received, min_amount_out).map_err(AmmError::from)?;
 
         require!(res.deposit != 0, AmmError::InvalidAmount);
         require!(res.withdraw != 0, AmmError::InvalidAmount);
 
-        // Slippage protection
-        require!(res.withdraw >= min_amount_out, AmmError::SlippageExceeded);
+        // Slippage protection, on what the user actually receives
+        let amount_out = post_fee_amount(mint_out, res.withdraw)?;
+        require!(amount_out >= min_amount_out, AmmError::SlippageExceeded);
 
-        // Perform the swap
-        self.transfer_tokens_to_vault(swap_x, res.deposit)?;
-        self.transfer_tokens_to_user(swap_x, res.withdraw)?;
+        self.oracle.update(reserve_x, reserve_y, Clock::get()?.unix_timestamp); // accumulate the price before the reserves move
 
+        // Perform the swap, the fee is already part of the deposit. The user sends exactly amount_in
+        // and the vault receives amount_received, so route_swap hops spend exactly what the last one paid out
+        self.transfer_tokens_to_vault(swap_x, amount_in)?;
+        self.transfer_tokens_to_user(swap_x, res.withdraw)?;
 
-        //transfer fee
-        self.transfer_fee_to_lps(swap_x, res.fee)?;
+        // the protocol keeps its share of the fee the vault received, the rest stays with the lps
+        let protocol_fee_amount = self.config.accrue_protocol_fee(swap_x, res.fee)?;
 
         // Emit event for tracking
         emit!(SwapEvent {
@@ -101,41 +125,91 @@ impl<'info> Swap<'info> {
             amount_in,
             min_amount_out,
             fee_amount: res.fee,
+            protocol_fee_amount,
         });
-        Ok(())
+        Ok(amount_out) // what the user actually received, route_swap feeds it into the next pool
 
     }
 
 
+    pub fn swap_exact_out(
+        &mut self,
+        swap_x: bool,        // true = swap X for Y, false = swap Y for X
+        amount_out: u64,     // exact amount of tokens the user wants to receive
+        max_amount_in: u64,  // maximum amount user is willing to spend (slippage protection)
+    ) -> Result<()> {
+        // Safety checks
+        require!(!self.config.locked, AmmError::PoolLocked);
+        require!(amount_out > 0, AmmError::InvalidAmount);
+
+        // unclaimed protocol fees are excluded from the reserves used for pricing
+        let (reserve_x, reserve_y) = self.config.reserves(self.vault_x.amount, self.vault_y.amount)?;
+        require!(reserve_x > 0 && reserve_y > 0, AmmError::NoLiquidityInPool);
+
+        let (reserve_in, reserve_out, mint_in, mint_out) = match swap_x {
+            true => (reserve_x, reserve_y, &self.mint_x, &self.mint_y),
+            false => (reserve_y, reserve_x, &self.mint_y, &self.mint_x)
+        };
+
+        // the vault sends enough for the user to receive exactly amount_out after any token-2022 transfer fee
+        let withdraw = pre_fee_amount(mint_out, amount_out)?;
+        let (deposit, fee_amount) = exact_out_amount_in(reserve_in, reserve_out, withdraw, self.config.fee)?;
+        let amount_in = pre_fee_amount(mint_in, deposit)?;
+
+        // Slippage protection
+        require!(amount_in <= max_amount_in, AmmError::SlippageExceeded);
+
+        self.oracle.update(reserve_x, reserve_y, Clock::get()?.unix_timestamp); // accumulate the price before the reserves move
+
+        // Perform the swap, the fee is already part of the deposit
+        self.transfer_tokens_to_vault(swap_x, amount_in)?;
+        self.transfer_tokens_to_user(swap_x, withdraw)?;
+
+        let protocol_fee_amount = self.config.accrue_protocol_fee(swap_x, fee_amount)?;
+
+        // Emit event for tracking
+        emit!(SwapExactOutEvent {
+            user: self.user.key(),
+            swap_x,
+            amount_out,
+            max_amount_in,
+            amount_in,
+            fee_amount,
+            protocol_fee_amount,
+        });
+        Ok(())
+    }
+
     // user deposit amount he wants to swap
     fn transfer_tokens_to_vault(&self, is_x: bool, amount: u64) -> Result<()> {
-        let (from, to) = if is_x {
-            (self.user_x.to_account_info(), self.vault_x.to_account_info())
+        let (from, to, mint, cpi_program) = if is_x {
+            (self.user_x.to_account_info(), self.vault_x.to_account_info(), &self.mint_x, self.token_program_x.to_account_info())
         } else {
-            (self.user_y.to_account_info(), self.vault_y.to_account_info())
+            (self.user_y.to_account_info(), self.vault_y.to_account_info(), &self.mint_y, self.token_program_y.to_account_info())
         };
 
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from,
+            mint: mint.to_account_info(),
             to,
             authority: self.user.to_account_info(),
         };
 
         let ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer(ctx, amount)
+        transfer_checked(ctx, amount, mint.decimals)
     }
 
     fn transfer_tokens_to_user(&self, is_x: bool, amount: u64) -> Result<()> {
-        let (from, to) = if is_x {
-            (self.vault_x.to_account_info(), self.user_x.to_account_info())
+        // the user sells x and receives y, or sells y and receives x
+        let (from, to, mint, cpi_program) = if is_x {
+            (self.vault_y.to_account_info(), self.user_y.to_account_info(), &self.mint_y, self.token_program_y.to_account_info())
         } else {
-            (self.vault_y.to_account_info(), self.user_y.to_account_info())
+            (self.vault_x.to_account_info(), self.user_x.to_account_info(), &self.mint_x, self.token_program_x.to_account_info())
         };
 
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from,
+            mint: mint.to_account_info(),
             to,
             authority: self.config.to_account_info(),
         };
@@ -149,32 +223,29 @@ impl<'info> Swap<'info> {
         let signer_seeds = &[&seeds[..]];
 
         let ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer(ctx, amount)
+        transfer_checked(ctx, amount, mint.decimals)
     }
 
-     pub fn transfer_fee_to_lps(&self, swap_x: bool, amount: u64) -> Result<()> {
-
-        let ( from, to ) = match swap_x {
-            true => (
-                self.user_x.to_account_info(),
-                self.vault_x.to_account_info()
-            ),
-            false => (
-                self.user_y.to_account_info(),
-                self.vault_y.to_account_info()
-            )
-        };
+}
 
-        let cpi_accounts = Transfer {
-            from: from.to_account_info(),
-            to: to.to_account_info(),
-            authority: self.user.to_account_info()
-        };
+// Input the vault must receive, fee included, for `amount_out` to leave the other vault.
+// Solves (reserve_in + dx) * (reserve_out - amount_out) = reserve_in * reserve_out for dx and
+// grosses it up by the fee, rounding up both times so the pool never loses to rounding.
+pub fn exact_out_amount_in(reserve_in: u64, reserve_out: u64, amount_out: u64, fee: u16) -> Result<(u64, u64)> {
+    require!(amount_out < reserve_out, AmmError::InsufficientBalance);
+    require!(fee < 10_000, AmmError::InvalidFee); // a 100% fee can't produce any output
 
-        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+    let numerator = (reserve_in as u128).checked_mul(amount_out as u128).ok_or(AmmError::Overflow)?;
+    let amount_in_after_fee = numerator.div_ceil((reserve_out - amount_out) as u128);
 
-        transfer(cpi_ctx, amount)
-    }
+    let amount_in = amount_in_after_fee
+        .checked_mul(10_000)
+        .ok_or(AmmError::Overflow)?
+        .div_ceil(10_000 - fee as u128);
+
+    let amount_in = u64::try_from(amount_in).map_err(|_| AmmError::Overflow)?;
+    let fee_amount = amount_in - amount_in_after_fee as u64; // amount_in_after_fee <= amount_in
+    Ok((amount_in, fee_amount))
 }
 
 // Event for tracking swaps
@@ -185,4 +256,16 @@ pub struct SwapEvent {
     pub amount_in: u64,
     pub min_amount_out: u64,
     pub fee_amount: u64,
+    pub protocol_fee_amount: u64,
+}
+
+#[event]
+pub struct SwapExactOutEvent {
+    pub user: Pubkey,
+    pub swap_x: bool,
+    pub amount_out: u64,
+    pub max_amount_in: u64,
+    pub amount_in: u64,
+    pub fee_amount: u64,
+    pub protocol_fee_amount: u64,
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, Config};
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+    pub authority: Signer<'info>, // the pool update authority stored on the config
+
+    #[account(
+        mut, // mutable because the pool settings change
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+        constraint = config.authority.is_some() @ AmmError::NoAuthoritySet,
+        constraint = config.authority == Some(authority.key()) @ AmmError::InvalidAuthority,
+    )]
+    pub config: Account<'info, Config>, //the escrow service / swap service / liquidity pool
+}
+
+impl<'info> Update<'info> {
+    pub fn lock(&mut self) -> Result<()> {
+        self.set_locked(true)
+    }
+
+    pub fn unlock(&mut self) -> Result<()> {
+        self.set_locked(false)
+    }
+
+    fn set_locked(&mut self, locked: bool) -> Result<()> {
+        self.config.locked = locked; // deposits, swaps and withdrawals check this flag
+
+        emit!(PoolLockEvent {
+            config: self.config.key(),
+            authority: self.authority.key(),
+            locked,
+        });
+        Ok(())
+    }
+
+    pub fn update_fee(&mut self, fee: u16) -> Result<()> {
+        require!(fee <= 10_000, AmmError::InvalidFee); // fee is in basis points, 10_000 = 100%
+
+        let old_fee = self.config.fee;
+        self.config.fee = fee;
+
+        emit!(FeeUpdateEvent {
+            config: self.config.key(),
+            authority: self.authority.key(),
+            old_fee,
+            new_fee: fee,
+        });
+        Ok(())
+    }
+
+    pub fn update_protocol_fee(&mut self, protocol_fee: u16) -> Result<()> {
+        require!(protocol_fee <= 10_000, AmmError::InvalidFee); // share of the swap fee in basis points, 10_000 = all of it
+
+        let old_protocol_fee = self.config.protocol_fee;
+        self.config.protocol_fee = protocol_fee;
+
+        emit!(ProtocolFeeUpdateEvent {
+            config: self.config.key(),
+            authority: self.authority.key(),
+            old_protocol_fee,
+            new_protocol_fee: protocol_fee,
+        });
+        Ok(())
+    }
+
+    pub fn set_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        self.update_authority(Some(new_authority))
+    }
+
+    // once renounced the pool settings can never be changed again
+    pub fn renounce_authority(&mut self) -> Result<()> {
+        self.update_authority(None)
+    }
+
+    fn update_authority(&mut self, new_authority: Option<Pubkey>) -> Result<()> {
+        self.config.authority = new_authority;
+
+        emit!(AuthorityUpdateEvent {
+            config: self.config.key(),
+            old_authority: self.authority.key(),
+            new_authority,
+        });
+        Ok(())
+    }
+}
+
+// Events for tracking pool admin changes
+#[event]
+pub struct PoolLockEvent {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub locked: bool,
+}
+
+#[event]
+pub struct FeeUpdateEvent {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub old_fee: u16,
+    pub new_fee: u16,
+}
+
+#[event]
+pub struct ProtocolFeeUpdateEvent {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub old_protocol_fee: u16,
+    pub new_protocol_fee: u16,
+}
+
+#[event]
+pub struct AuthorityUpdateEvent {
+    pub config: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+}
\ No newline at end of file
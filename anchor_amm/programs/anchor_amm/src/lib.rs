@@ -6,6 +6,7 @@ pub mod constants;
 pub mod error;
 pub mod state;
 pub mod instructions;
+pub mod utils;
 
 pub use constants::*;
 pub use state::*;
@@ -25,10 +26,59 @@ pub mod anchor_amm {
     }
 
     pub fn swap(ctx: Context<Swap>, swap_x: bool, amount_in: u64, min_amount_out: u64) -> Result<()> {
-        ctx.accounts.swap(swap_x, amount_in, min_amount_out)
+        ctx.accounts.swap(swap_x, amount_in, min_amount_out)?;
+        Ok(())
+    }
+
+    pub fn route_swap<'info>(ctx: Context<'_, '_, 'info, 'info, RouteSwap<'info>>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        ctx.accounts.route_swap(amount_in, min_amount_out, ctx.remaining_accounts)
+    }
+
+    pub fn swap_exact_out(ctx: Context<Swap>, swap_x: bool, amount_out: u64, max_amount_in: u64) -> Result<()> {
+        ctx.accounts.swap_exact_out(swap_x, amount_out, max_amount_in)
     }
     
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64, min_x: u64, min_y: u64) -> Result<()> {
         ctx.accounts.withdraw(amount, min_x, min_y) 
 }
+
+    pub fn observe(ctx: Context<Observe>, window: u32) -> Result<Twap> {
+        ctx.accounts.observe(window)
+    }
+
+    pub fn lock(ctx: Context<Update>) -> Result<()> {
+        ctx.accounts.lock()
+    }
+
+    pub fn unlock(ctx: Context<Update>) -> Result<()> {
+        ctx.accounts.unlock()
+    }
+
+    pub fn update_fee(ctx: Context<Update>, fee: u16) -> Result<()> {
+        ctx.accounts.update_fee(fee)
+    }
+
+    pub fn update_protocol_fee(ctx: Context<Update>, protocol_fee: u16) -> Result<()> {
+        ctx.accounts.update_protocol_fee(protocol_fee)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>, _seed: u64) -> Result<()> {
+        ctx.accounts.migrate_config()
+    }
+
+    pub fn init_oracle(ctx: Context<InitOracle>) -> Result<()> {
+        ctx.accounts.init_oracle(ctx.bumps)
+    }
+
+    pub fn claim_protocol_fees(ctx: Context<ClaimProtocolFees>) -> Result<()> {
+        ctx.accounts.claim_protocol_fees()
+    }
+
+    pub fn set_authority(ctx: Context<Update>, new_authority: Pubkey) -> Result<()> {
+        ctx.accounts.set_authority(new_authority)
+    }
+
+    pub fn renounce_authority(ctx: Context<Update>) -> Result<()> {
+        ctx.accounts.renounce_authority()
+    }
 }
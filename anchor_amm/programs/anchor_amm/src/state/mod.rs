@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::error::AmmError;
+
+pub mod oracle;
+
+pub use oracle::*;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Config{
@@ -11,4 +17,32 @@ pub struct Config{
     pub locked: bool,
     pub config_bump: u8,
     pub lp_bump: u8,
+    pub protocol_fee: u16, // share of every swap fee kept for the protocol, in basis points
+    pub protocol_fees_x: u64, // accrued x fees claimable by the authority
+    pub protocol_fees_y: u64, // accrued y fees claimable by the authority
+}
+
+impl Config {
+    // vault balances minus unclaimed protocol fees, this is what the lps own and what the curve prices on
+    pub fn reserves(&self, vault_x: u64, vault_y: u64) -> Result<(u64, u64)> {
+        let reserve_x = vault_x.checked_sub(self.protocol_fees_x).ok_or(AmmError::Underflow)?;
+        let reserve_y = vault_y.checked_sub(self.protocol_fees_y).ok_or(AmmError::Underflow)?;
+        Ok((reserve_x, reserve_y))
+    }
+
+    // sets aside the protocol share of a swap fee paid in x or y
+    pub fn accrue_protocol_fee(&mut self, is_x: bool, swap_fee: u64) -> Result<u64> {
+        let protocol_amount = (swap_fee as u128)
+            .checked_mul(self.protocol_fee as u128)
+            .ok_or(AmmError::Overflow)?
+            / 10_000;
+        let protocol_amount = protocol_amount as u64; // never more than swap_fee
+
+        let accrued = match is_x {
+            true => &mut self.protocol_fees_x,
+            false => &mut self.protocol_fees_y,
+        };
+        *accrued = accrued.checked_add(protocol_amount).ok_or(AmmError::Overflow)?;
+        Ok(protocol_amount)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::AmmError, OBSERVATION_INTERVAL};
+
+pub const OBSERVATION_CAPACITY: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct Observation {
+    pub timestamp: i64,
+    pub price_x_cumulative: u128,
+    pub price_y_cumulative: u128,
+}
+
+// time weighted average prices as Q64.64 fixed point numbers, price_x is y per x and price_y is x per y
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Twap {
+    pub price_x: u128,
+    pub price_y: u128,
+    pub window: i64, // actual seconds averaged over, at least the requested window
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Oracle {
+    pub config: Pubkey,
+    pub price_x_cumulative: u128, // sum of price_x * seconds, wraps on overflow like a uniswap v2 accumulator
+    pub price_y_cumulative: u128, // sum of price_y * seconds, wraps on overflow like a uniswap v2 accumulator
+    pub last_update_ts: i64,
+    pub observation_index: u16, // slot of the newest observation
+    pub observation_count: u16, // filled slots, grows up to OBSERVATION_CAPACITY
+    pub observations: [Observation; OBSERVATION_CAPACITY],
+    pub bump: u8,
+}
+
+impl Oracle {
+    // spot prices as Q64.64, None while either side of the pool is empty
+    pub fn spot_prices(reserve_x: u64, reserve_y: u64) -> Option<(u128, u128)> {
+        if reserve_x == 0 || reserve_y == 0 {
+            return None;
+        }
+        let price_x = ((reserve_y as u128) << 64) / reserve_x as u128;
+        let price_y = ((reserve_x as u128) << 64) / reserve_y as u128;
+        Some((price_x, price_y))
+    }
+
+    pub fn init(&mut self, config: Pubkey, now: i64, bump: u8) {
+        self.config = config;
+        self.last_update_ts = now;
+        self.observations[0] = Observation { timestamp: now, ..Default::default() };
+        self.observation_index = 0;
+        self.observation_count = 1;
+        self.bump = bump;
+    }
+
+    // accumulators at `now`, extrapolated with the reserves in place since the last update
+    fn cumulative_at(&self, reserve_x: u64, reserve_y: u64, now: i64) -> (u128, u128) {
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+        match Self::spot_prices(reserve_x, reserve_y) {
+            Some((price_x, price_y)) => (
+                self.price_x_cumulative.wrapping_add(price_x.wrapping_mul(elapsed)),
+                self.price_y_cumulative.wrapping_add(price_y.wrapping_mul(elapsed)),
+            ),
+            None => (self.price_x_cumulative, self.price_y_cumulative),
+        }
+    }
+
+    // call with the reserves from before a swap, deposit or withdraw changes them
+    pub fn update(&mut self, reserve_x: u64, reserve_y: u64, now: i64) {
+        if now <= self.last_update_ts {
+            return; // already accumulated this second, so a single transaction can't move the average
+        }
+
+        (self.price_x_cumulative, self.price_y_cumulative) = self.cumulative_at(reserve_x, reserve_y, now);
+        self.last_update_ts = now;
+
+        let newest = self.observations[self.observation_index as usize];
+        if now - newest.timestamp >= OBSERVATION_INTERVAL {
+            self.observation_index = (self.observation_index + 1) % OBSERVATION_CAPACITY as u16;
+            self.observation_count = (self.observation_count + 1).min(OBSERVATION_CAPACITY as u16);
+            self.observations[self.observation_index as usize] = Observation {
+                timestamp: now,
+                price_x_cumulative: self.price_x_cumulative,
+                price_y_cumulative: self.price_y_cumulative,
+            };
+        }
+    }
+
+    // twap over at least `window` seconds ending now, using the newest observation old enough
+    pub fn observe(&self, reserve_x: u64, reserve_y: u64, now: i64, window: u32) -> Result<Twap> {
+        require!(window > 0, AmmError::InvalidAmount);
+        let target = now.checked_sub(window as i64).ok_or(AmmError::Underflow)?;
+
+        let start = self.observations[..self.observation_count as usize]
+            .iter()
+            .filter(|observation| observation.timestamp <= target)
+            .max_by_key(|observation| observation.timestamp)
+            .ok_or(AmmError::InsufficientOracleHistory)?;
+
+        let (price_x_cumulative, price_y_cumulative) = self.cumulative_at(reserve_x, reserve_y, now);
+        let elapsed = now - start.timestamp; // at least window, so never zero
+
+        Ok(Twap {
+            price_x: price_x_cumulative.wrapping_sub(start.price_x_cumulative) / elapsed as u128,
+            price_y: price_y_cumulative.wrapping_sub(start.price_y_cumulative) / elapsed as u128,
+            window: elapsed,
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Mint as MintState,
+    },
+    token_interface::Mint,
+};
+
+use crate::error::AmmError;
+
+// extensions that don't let anyone move, freeze or hook into the vault balances
+pub const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+// rejects Token-2022 mints with extensions such as a permanent delegate or transfer hook
+pub fn validate_mint_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?; // classic mints have no extensions
+
+    for extension in mint_state.get_extension_types()? {
+        require!(SUPPORTED_MINT_EXTENSIONS.contains(&extension), AmmError::UnsupportedMintExtension);
+    }
+    Ok(())
+}
+
+// fee withheld by a TransferFee mint when `amount` is sent, zero for every other mint
+pub fn transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(AmmError::Overflow)?,
+        Err(_) => 0,
+    };
+    Ok(fee)
+}
+
+// amount that actually arrives when `amount` is sent
+pub fn post_fee_amount(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let fee = transfer_fee(mint, amount)?;
+    Ok(amount.checked_sub(fee).ok_or(AmmError::Underflow)?)
+}
+
+// amount that has to be sent so that `amount` arrives
+pub fn pre_fee_amount(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(AmmError::Overflow)?,
+        Err(_) => 0,
+    };
+    Ok(amount.checked_add(fee).ok_or(AmmError::Overflow)?)
+}
\ No newline at end of file
@@ -4,6 +4,13 @@ import { Program } from "@coral-xyz/anchor";
 import { AnchorAmm } from "../target/types/anchor_amm"; // Import the AMM program type
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  getMintLen,
+  getTransferFeeAmount,
   createMint,
   getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
@@ -12,7 +19,7 @@ import {
 } from "@solana/spl-token"; // SPL token utilities for creating and managing tokens
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
-import { Commitment, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { Commitment, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction } from "@solana/web3.js";
 import { BN } from "bn.js"; // BigNumber for handling large numbers
 
 // Define the commitment level for Solana transactions
@@ -137,6 +144,8 @@ describe("anchor_amm", () => {
       vaultY: vaultY, // Vault for token Y
       config: config, // Config account for the pool
       tokenProgram: TOKEN_PROGRAM_ID, // SPL Token program
+      tokenProgramX: TOKEN_PROGRAM_ID, // Token program that owns mint X
+      tokenProgramY: TOKEN_PROGRAM_ID, // Token program that owns mint Y
       associatedTokenProgram: ASSOCIATED_PROGRAM_ID, // Associated token program
       systemProgram: SystemProgram.programId, // Solana system program
     })
@@ -144,6 +153,43 @@ describe("anchor_amm", () => {
     console.log("Your transaction signature", tx);
   });
 
+  // Test case to migrate a pool config, a pool created with the current layout is left as is
+  it("Migrate Config", async () => {
+    const before = await connection.getAccountInfo(config);
+
+    const tx = await program.methods.migrateConfig(seed)
+    .accountsPartial({
+      payer: provider.publicKey,
+      config: config,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const after = await connection.getAccountInfo(config);
+    if (after.data.length !== before.data.length) throw new Error("current config should not grow");
+    if (after.lamports !== before.lamports) throw new Error("current config should not be topped up");
+
+    const configAccount = await program.account.config.fetch(config);
+    if (configAccount.protocolFee !== 0) throw new Error("protocol fee should still be unset");
+  });
+
+  // Test case to create a missing oracle, initialize already created this pool's
+  it("Init Oracle", async () => {
+    try {
+      await program.methods.initOracle()
+      .accountsPartial({
+        payer: provider.publicKey,
+        config: config,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+      throw new Error("a pool's oracle should only be created once");
+    } catch (err) {
+      if (!err.toString().includes("already in use")) throw err;
+    }
+  });
+
   // Test case to deposit tokens into the AMM pool
   it("Deposit Tokens", async () => {
     // Call the deposit method to add liquidity
@@ -164,6 +210,8 @@ describe("anchor_amm", () => {
       userY: userAtaY, // User's token Y account
       userLp: userLp, // User's LP token account
       tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgramX: TOKEN_PROGRAM_ID, // Token program that owns mint X
+      tokenProgramY: TOKEN_PROGRAM_ID, // Token program that owns mint Y
       associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
@@ -186,6 +234,8 @@ console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
 
   // Test case to swap tokens in the AMM pool
   it("Swap Tokens", async () => {
+    const userXBefore = await getAccount(connection, userAtaX);
+
     // Call the swap method to exchange tokens
     const tx = await program.methods.swap(
       true, // Direction: true for X to Y, false for Y to X
@@ -201,13 +251,18 @@ console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
       vaultY: vaultY,
       userX: userAtaX,
       userY: userAtaY,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgramX: TOKEN_PROGRAM_ID, // Token program that owns mint X
+      tokenProgramY: TOKEN_PROGRAM_ID, // Token program that owns mint Y
       associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
     .rpc();
     console.log("Your transaction signature", tx);
     
+    // The fee is part of the amount in, nothing else leaves the user's account
+    const userXAfter = await getAccount(connection, userAtaX);
+    if (userXBefore.amount - userXAfter.amount !== BigInt(4_000_000)) throw new Error("swap charged more than the amount in");
+
     const vaultXBalance = await getAccount(connection, vaultX);
     const vaultYBalance = await getAccount(connection, vaultY);
     const userAtaXBalance = await getAccount(connection, userAtaX);
@@ -222,13 +277,279 @@ console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
 
   });
 
+  // Test case to take a protocol share of swap fees and claim it as the pool authority
+  it("Claim Protocol Fees", async () => {
+    await program.methods.updateProtocolFee(2000) // 2000 basis points = 20% of every swap fee
+    .accountsPartial({
+      authority: provider.publicKey,
+      config: config,
+    })
+    .rpc();
+
+    await program.methods.swap(true, new BN(1_000_000), new BN(1))
+    .accountsPartial({
+      user: provider.publicKey,
+      mintX: mintX,
+      mintY: mintY,
+      config: config,
+      vaultX: vaultX,
+      vaultY: vaultY,
+      userX: userAtaX,
+      userY: userAtaY,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+    const accrued = await program.account.config.fetch(config);
+    if (accrued.protocolFeesX.isZero()) throw new Error("protocol fee was not accrued");
+
+    const destinationX = await getAccount(connection, userAtaX);
+    const tx = await program.methods.claimProtocolFees()
+    .accountsPartial({
+      authority: provider.publicKey,
+      mintX: mintX,
+      mintY: mintY,
+      config: config,
+      vaultX: vaultX,
+      vaultY: vaultY,
+      destinationX: userAtaX, // Any token accounts chosen by the authority
+      destinationY: userAtaY,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const claimed = await program.account.config.fetch(config);
+    const destinationXAfter = await getAccount(connection, userAtaX);
+    if (!claimed.protocolFeesX.isZero()) throw new Error("accrued fees were not reset");
+    if (destinationXAfter.amount - destinationX.amount !== BigInt(accrued.protocolFeesX.toString())) {
+      throw new Error("destination didn't receive the accrued fees");
+    }
+  });
+
+  // Test case to receive an exact amount of Y while capping the X spent
+  it("Swap Exact Out", async () => {
+    const swapAccounts = {
+      user: provider.publicKey,
+      mintX: mintX,
+      mintY: mintY,
+      config: config,
+      vaultX: vaultX,
+      vaultY: vaultY,
+      userX: userAtaX,
+      userY: userAtaY,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+
+    try {
+      await program.methods.swapExactOut(true, new BN(100_000), new BN(1)) // Max input far too low
+      .accountsPartial(swapAccounts)
+      .rpc();
+      throw new Error("swap above max_amount_in should fail");
+    } catch (err) {
+      if (!err.toString().includes("SlippageExceeded")) throw err;
+    }
+
+    const userYBefore = await getAccount(connection, userAtaY);
+    const tx = await program.methods.swapExactOut(
+      true, // Direction: true for X to Y, false for Y to X
+      new BN(100_000), // Exact amount of Y to receive
+      new BN(1_000_000) // Maximum amount of X to spend
+    )
+    .accountsPartial(swapAccounts)
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const userYAfter = await getAccount(connection, userAtaY);
+    if (userYAfter.amount - userYBefore.amount !== BigInt(100_000)) throw new Error("didn't receive the exact amount out");
+  });
+
+  // Test case to read the time weighted average price from the pool oracle
+  it("Observe TWAP", async () => {
+    const observeAccounts = {
+      config: config,
+      vaultX: vaultX,
+      vaultY: vaultY,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+    };
+
+    // The pool was initialized a few seconds ago, so a one second window is always covered
+    const twap = await program.methods.observe(1)
+    .accountsPartial(observeAccounts)
+    .view();
+    console.log(`TWAP X in Y (Q64.64): ${twap.priceX.toString()} over ${twap.window.toString()}s`);
+    if (twap.priceX.isZero() || twap.priceY.isZero()) throw new Error("twap should be positive");
+
+    try {
+      await program.methods.observe(86_400) // A day of history doesn't exist yet
+      .accountsPartial(observeAccounts)
+      .view();
+      throw new Error("window longer than the history should fail");
+    } catch (err) {
+      if (!err.toString().includes("InsufficientOracleHistory")) throw err;
+    }
+  });
+
+  // Second pool for routed swaps, it trades Y against a new token Z
+  const routeSeed = new BN(263);
+  const routeConfig = PublicKey.findProgramAddressSync([
+    Buffer.from("config"),
+    routeSeed.toBuffer("le", 8)],
+    program.programId)[0];
+  const routeMintLp = PublicKey.findProgramAddressSync([
+    Buffer.from('lp'),
+    routeConfig.toBuffer()],
+    program.programId)[0];
+  let mintZ: PublicKey; // Mint address for token Z
+  let routeVaultY: PublicKey; // Vault for token Y in the Y/Z pool
+  let routeVaultZ: PublicKey; // Vault for token Z in the Y/Z pool
+  let userAtaZ: PublicKey; // User's associated token account for token Z
+
+  const oracleFor = (pool: PublicKey) => PublicKey.findProgramAddressSync([
+    Buffer.from("oracle"),
+    pool.toBuffer()],
+    program.programId)[0];
+
+  // config, oracle, mint_x, mint_y, vault_x, vault_y, user_x, user_y, token_program_x, token_program_y for every hop
+  const hop = (pool: PublicKey, poolMintX: PublicKey, poolMintY: PublicKey, poolVaultX: PublicKey, poolVaultY: PublicKey, poolUserX: PublicKey, poolUserY: PublicKey) => [
+    { pubkey: pool, isSigner: false, isWritable: true },
+    { pubkey: oracleFor(pool), isSigner: false, isWritable: true },
+    { pubkey: poolMintX, isSigner: false, isWritable: false },
+    { pubkey: poolMintY, isSigner: false, isWritable: false },
+    { pubkey: poolVaultX, isSigner: false, isWritable: true },
+    { pubkey: poolVaultY, isSigner: false, isWritable: true },
+    { pubkey: poolUserX, isSigner: false, isWritable: true },
+    { pubkey: poolUserY, isSigner: false, isWritable: true },
+    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+  ];
+
+  // X -> Y through the first pool, then Y -> Z through the second
+  const routeFor = (userX: PublicKey, userY: PublicKey, userZ: PublicKey) => [
+    ...hop(config, mintX, mintY, vaultX, vaultY, userX, userY),
+    ...hop(routeConfig, mintY, mintZ, routeVaultY, routeVaultZ, userY, userZ),
+  ];
+
+  const routeAccounts = (user: PublicKey) => ({
+    user,
+    mintIn: mintX,
+    mintOut: mintZ,
+    associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+  });
+
+  // Test case to swap X for Z through the X/Y pool and a second Y/Z pool in one transaction
+  it("Route Swap", async () => {
+    mintZ = await createMint(connection, payer.payer, provider.publicKey, null, 6);
+    userAtaZ = (await getOrCreateAssociatedTokenAccount(connection, payer.payer, mintZ, provider.publicKey)).address;
+    await mintTo(connection, payer.payer, mintZ, userAtaZ, provider.publicKey, 200_000_000);
+    routeVaultY = getAssociatedTokenAddressSync(mintY, routeConfig, true);
+    routeVaultZ = getAssociatedTokenAddressSync(mintZ, routeConfig, true);
+
+    await program.methods.initialize(routeSeed, 300, provider.publicKey)
+    .accountsPartial({
+      initializer: provider.publicKey,
+      mintX: mintY,
+      mintY: mintZ,
+      mintLp: routeMintLp,
+      vaultX: routeVaultY,
+      vaultY: routeVaultZ,
+      config: routeConfig,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+    await program.methods.deposit(new BN(1000_000_000), new BN(10_000_000), new BN(10_000_000))
+    .accountsPartial({
+      user: provider.publicKey,
+      mintX: mintY,
+      mintY: mintZ,
+      config: routeConfig,
+      mintLp: routeMintLp,
+      vaultX: routeVaultY,
+      vaultY: routeVaultZ,
+      userX: userAtaY,
+      userY: userAtaZ,
+      userLp: getAssociatedTokenAddressSync(routeMintLp, provider.publicKey),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgramX: TOKEN_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+    const route = routeFor(userAtaX, userAtaY, userAtaZ);
+
+    try {
+      await program.methods.routeSwap(new BN(500_000), new BN(10_000_000)) // Minimum out is unreachable
+      .accountsPartial(routeAccounts(provider.publicKey))
+      .remainingAccounts(route)
+      .rpc();
+      throw new Error("route below min_amount_out should fail");
+    } catch (err) {
+      if (!err.toString().includes("SlippageExceeded")) throw err;
+    }
+
+    const userYBefore = await getAccount(connection, userAtaY);
+    const userZBefore = await getAccount(connection, userAtaZ);
+    const tx = await program.methods.routeSwap(
+      new BN(500_000), // Amount of X to swap
+      new BN(1) // Minimum amount of Z to receive at the end of the route
+    )
+    .accountsPartial(routeAccounts(provider.publicKey))
+    .remainingAccounts(route)
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const userYAfter = await getAccount(connection, userAtaY);
+    const userZAfter = await getAccount(connection, userAtaZ);
+    if (userZAfter.amount <= userZBefore.amount) throw new Error("route didn't pay out Z");
+    if (userYAfter.amount !== userYBefore.amount) throw new Error("second hop didn't spend exactly what the first paid out");
+  });
+
+  // Test case to route through a token the user holds none of before the swap
+  it("Route Swap with an empty intermediate account", async () => {
+    const trader = Keypair.generate();
+    const traderX = (await getOrCreateAssociatedTokenAccount(connection, payer.payer, mintX, trader.publicKey)).address;
+    const traderY = (await getOrCreateAssociatedTokenAccount(connection, payer.payer, mintY, trader.publicKey)).address;
+    const traderZ = (await getOrCreateAssociatedTokenAccount(connection, payer.payer, mintZ, trader.publicKey)).address;
+    await mintTo(connection, payer.payer, mintX, traderX, provider.publicKey, 500_000);
+
+    const tx = await program.methods.routeSwap(new BN(500_000), new BN(1))
+    .accountsPartial(routeAccounts(trader.publicKey))
+    .remainingAccounts(routeFor(traderX, traderY, traderZ))
+    .signers([trader])
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const traderXAfter = await getAccount(connection, traderX);
+    const traderYAfter = await getAccount(connection, traderY);
+    const traderZAfter = await getAccount(connection, traderZ);
+    if (traderXAfter.amount !== BigInt(0)) throw new Error("route didn't spend exactly the amount in");
+    if (traderYAfter.amount !== BigInt(0)) throw new Error("intermediate Y was left over or overdrawn");
+    if (traderZAfter.amount === BigInt(0)) throw new Error("route didn't pay out Z");
+  });
+
   // Test case to withdraw tokens from the AMM pool
   it("Withdraw Tokens", async () => {
     // Call the withdraw method to remove liquidity
     const tx = await program.methods.withdraw(
       new BN(1000_000_000), // LP token amount to burn
       new BN(10_000_000), // Min token X to receive
-      new BN(10_000_000) // Min token Y to receive
+      new BN(1_000_000) // Min token Y to receive, the swap above took y out of the pool
     )
     .accountsPartial({
       user: provider.publicKey, // User withdrawing tokens
@@ -242,6 +563,8 @@ console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
       userY: userAtaY,
       userLp: userLp,
       tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgramX: TOKEN_PROGRAM_ID, // Token program that owns mint X
+      tokenProgramY: TOKEN_PROGRAM_ID, // Token program that owns mint Y
       associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
@@ -261,4 +584,301 @@ console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
     console.log(`User LP Balance: ${userLpBalance.amount.toString()}`);
 
   });
+
+  // Token-2022 pool: token X charges a 1% transfer fee, token Y is a classic SPL token
+  describe("token-2022 pool", () => {
+    const feeSeed = new BN(261);
+
+    let feeMintX: PublicKey;
+    let feeMintY: PublicKey;
+    let feeVaultX: PublicKey;
+    let feeVaultY: PublicKey;
+    let feeUserX: PublicKey;
+    let feeUserY: PublicKey;
+
+    const feeConfig = PublicKey.findProgramAddressSync([
+      Buffer.from("config"),
+      feeSeed.toBuffer("le", 8)],
+      program.programId)[0];
+
+    const feeMintLp = PublicKey.findProgramAddressSync([
+      Buffer.from('lp'),
+      feeConfig.toBuffer()],
+      program.programId)[0];
+
+    // Create a Token-2022 mint with the given extensions initialized before the mint itself
+    const createToken2022Mint = async (extensions: ExtensionType[], initExtensions: (mint: PublicKey) => TransactionInstruction[]) => {
+      const mint = Keypair.generate();
+      const mintLen = getMintLen(extensions);
+      const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+
+      const tx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: provider.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        ...initExtensions(mint.publicKey),
+        createInitializeMintInstruction(mint.publicKey, 6, provider.publicKey, null, TOKEN_2022_PROGRAM_ID),
+      );
+      await sendAndConfirmTransaction(connection, tx, [payer.payer, mint], { commitment });
+      return mint.publicKey;
+    };
+
+    const feePoolAccounts = () => ({
+      user: provider.publicKey,
+      mintX: feeMintX,
+      mintY: feeMintY,
+      config: feeConfig,
+      mintLp: feeMintLp,
+      vaultX: feeVaultX,
+      vaultY: feeVaultY,
+      userX: feeUserX,
+      userY: feeUserY,
+      userLp: getAssociatedTokenAddressSync(feeMintLp, provider.publicKey),
+      tokenProgram: TOKEN_PROGRAM_ID, // LP mint stays a classic SPL token
+      tokenProgramX: TOKEN_2022_PROGRAM_ID,
+      tokenProgramY: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("Initialize pool with a transfer fee mint", async () => {
+      feeMintX = await createToken2022Mint([ExtensionType.TransferFeeConfig], (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          provider.publicKey,
+          provider.publicKey,
+          100, // 100 basis points = 1% transfer fee
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]);
+      feeMintY = await createMint(connection, payer.payer, provider.publicKey, null, 6);
+
+      feeVaultX = getAssociatedTokenAddressSync(feeMintX, feeConfig, true, TOKEN_2022_PROGRAM_ID);
+      feeVaultY = getAssociatedTokenAddressSync(feeMintY, feeConfig, true);
+
+      feeUserX = (await getOrCreateAssociatedTokenAccount(
+        connection, payer.payer, feeMintX, provider.publicKey, false, commitment, undefined, TOKEN_2022_PROGRAM_ID
+      )).address;
+      feeUserY = (await getOrCreateAssociatedTokenAccount(
+        connection, payer.payer, feeMintY, provider.publicKey
+      )).address;
+
+      await mintTo(connection, payer.payer, feeMintX, feeUserX, provider.publicKey, 200_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+      await mintTo(connection, payer.payer, feeMintY, feeUserY, provider.publicKey, 200_000_000);
+
+      const tx = await program.methods.initialize(feeSeed, 500, provider.publicKey)
+      .accountsPartial({
+        initializer: provider.publicKey,
+        mintX: feeMintX,
+        mintY: feeMintY,
+        mintLp: feeMintLp,
+        vaultX: feeVaultX,
+        vaultY: feeVaultY,
+        config: feeConfig,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgramX: TOKEN_2022_PROGRAM_ID,
+        tokenProgramY: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+      console.log("Your transaction signature", tx);
+    });
+
+    it("Deposit and swap through the transfer fee", async () => {
+      await program.methods.deposit(new BN(1000_000_000), new BN(10_000_000), new BN(10_000_000))
+      .accountsPartial(feePoolAccounts())
+      .rpc();
+
+      const vaultXBefore = await getAccount(connection, feeVaultX, commitment, TOKEN_2022_PROGRAM_ID);
+      const userXBefore = await getAccount(connection, feeUserX, commitment, TOKEN_2022_PROGRAM_ID);
+
+      const { user, mintLp, userLp, tokenProgram, ...swapAccounts } = feePoolAccounts();
+      const tx = await program.methods.swap(true, new BN(1_000_000), new BN(1))
+      .accountsPartial({ user, ...swapAccounts })
+      .rpc();
+      console.log("Your transaction signature", tx);
+
+      const vaultXAfter = await getAccount(connection, feeVaultX, commitment, TOKEN_2022_PROGRAM_ID);
+      const userXAfter = await getAccount(connection, feeUserX, commitment, TOKEN_2022_PROGRAM_ID);
+
+      // Whatever the user sent minus what the vault received is withheld by the mint, not lost by the pool
+      const sent = userXBefore.amount - userXAfter.amount;
+      const received = vaultXAfter.amount - vaultXBefore.amount;
+      const withheld = getTransferFeeAmount(vaultXAfter).withheldAmount - getTransferFeeAmount(vaultXBefore).withheldAmount;
+      if (received >= sent) throw new Error("transfer fee was not charged");
+      if (sent - received !== withheld) throw new Error("vault balance doesn't match the amount received");
+    });
+
+    it("Reject a mint with a permanent delegate", async () => {
+      const delegatedMint = await createToken2022Mint([ExtensionType.PermanentDelegate], (mint) => [
+        createInitializePermanentDelegateInstruction(mint, provider.publicKey, TOKEN_2022_PROGRAM_ID),
+      ]);
+      const badSeed = new BN(262);
+      const badConfig = PublicKey.findProgramAddressSync([
+        Buffer.from("config"),
+        badSeed.toBuffer("le", 8)],
+        program.programId)[0];
+
+      try {
+        await program.methods.initialize(badSeed, 500, provider.publicKey)
+        .accountsPartial({
+          initializer: provider.publicKey,
+          mintX: delegatedMint,
+          mintY: feeMintY,
+          mintLp: PublicKey.findProgramAddressSync([Buffer.from('lp'), badConfig.toBuffer()], program.programId)[0],
+          vaultX: getAssociatedTokenAddressSync(delegatedMint, badConfig, true, TOKEN_2022_PROGRAM_ID),
+          vaultY: getAssociatedTokenAddressSync(feeMintY, badConfig, true),
+          config: badConfig,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgramX: TOKEN_2022_PROGRAM_ID,
+          tokenProgramY: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+        throw new Error("permanent delegate mint should be rejected");
+      } catch (err) {
+        if (!err.toString().includes("UnsupportedMintExtension")) throw err;
+      }
+    });
+  });
+
+  // Test case to lock the pool so deposits, swaps and withdrawals are rejected
+  it("Lock Pool", async () => {
+    const tx = await program.methods.lock()
+    .accountsPartial({
+      authority: provider.publicKey, // Pool update authority
+      config: config,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const configAccount = await program.account.config.fetch(config);
+    if (!configAccount.locked) throw new Error("pool should be locked");
+
+    try {
+      await program.methods.swap(true, new BN(1_000_000), new BN(1))
+      .accountsPartial({
+        user: provider.publicKey,
+        mintX: mintX,
+        mintY: mintY,
+        config: config,
+        vaultX: vaultX,
+        vaultY: vaultY,
+        userX: userAtaX,
+        userY: userAtaY,
+        tokenProgramX: TOKEN_PROGRAM_ID, // Token program that owns mint X
+        tokenProgramY: TOKEN_PROGRAM_ID, // Token program that owns mint Y
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+      throw new Error("swap on a locked pool should fail");
+    } catch (err) {
+      if (!err.toString().includes("PoolLocked")) throw err;
+    }
+  });
+
+  // Test case to unlock the pool again
+  it("Unlock Pool", async () => {
+    const tx = await program.methods.unlock()
+    .accountsPartial({
+      authority: provider.publicKey,
+      config: config,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const configAccount = await program.account.config.fetch(config);
+    if (configAccount.locked) throw new Error("pool should be unlocked");
+  });
+
+  // Test case to update the pool fee and reject fees above 100%
+  it("Update Fee", async () => {
+    const tx = await program.methods.updateFee(300) // 300 basis points = 3%
+    .accountsPartial({
+      authority: provider.publicKey,
+      config: config,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    const configAccount = await program.account.config.fetch(config);
+    if (configAccount.fee !== 300) throw new Error("fee was not updated");
+
+    try {
+      await program.methods.updateFee(10_001)
+      .accountsPartial({
+        authority: provider.publicKey,
+        config: config,
+      })
+      .rpc();
+      throw new Error("fee above 100% should fail");
+    } catch (err) {
+      if (!err.toString().includes("InvalidFee")) throw err;
+    }
+  });
+
+  // Test case to hand the pool to a new authority and reject the old one
+  it("Set Authority", async () => {
+    const newAuthority = Keypair.generate();
+
+    const tx = await program.methods.setAuthority(newAuthority.publicKey)
+    .accountsPartial({
+      authority: provider.publicKey,
+      config: config,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    try {
+      await program.methods.lock()
+      .accountsPartial({
+        authority: provider.publicKey,
+        config: config,
+      })
+      .rpc();
+      throw new Error("old authority should be rejected");
+    } catch (err) {
+      if (!err.toString().includes("InvalidAuthority")) throw err;
+    }
+
+    // Hand the pool back so the remaining tests can use the provider wallet
+    await program.methods.setAuthority(provider.publicKey)
+    .accountsPartial({
+      authority: newAuthority.publicKey,
+      config: config,
+    })
+    .signers([newAuthority])
+    .rpc();
+  });
+
+  // Test case to renounce the authority, after which the pool can't be updated
+  it("Renounce Authority", async () => {
+    const tx = await program.methods.renounceAuthority()
+    .accountsPartial({
+      authority: provider.publicKey,
+      config: config,
+    })
+    .rpc();
+    console.log("Your transaction signature", tx);
+
+    try {
+      await program.methods.unlock()
+      .accountsPartial({
+        authority: provider.publicKey,
+        config: config,
+      })
+      .rpc();
+      throw new Error("update without an authority should fail");
+    } catch (err) {
+      if (!err.toString().includes("NoAuthoritySet")) throw err;
+    }
+  });
 });
\ No newline at end of file